@@ -0,0 +1,376 @@
+//! Per-secret analysis of how a strategy plays every number in a range, and
+//! post-game analysis of every guess made in a game.
+use std::{cmp::Ordering, io};
+
+use serde_derive::Serialize;
+
+use super::{
+    auto_guesser::{self, Bisection, Strategy},
+    efficiency,
+    knowledge::KnowledgeState,
+    AutoNumberGuesser, NumberGuessingGame,
+};
+
+/// The most secrets a [`SecretReport`] plays.
+pub const MAX_SECRETS: u128 = 1_000_000;
+
+/// Characters used for the number line heatmap, from the fewest to the most guesses.
+const SHADES: &[u8] = b" .:-=+*#%@";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shade() {
+        assert_eq!(shade(1, 1, 1), '@');
+        assert_eq!(shade(1, 1, 5), ' ');
+        assert_eq!(shade(5, 1, 5), '@');
+        assert_eq!(shade(3, 1, 5), '=');
+    }
+}
+
+/// The result of guessing a single secret.
+#[derive(Debug, Clone)]
+pub struct SecretResult {
+    /// The secret number.
+    secret: i128,
+    /// The guesses made, in order.
+    path: Vec<i128>,
+}
+
+impl SecretResult {
+    /// Gets the secret number.
+    pub fn secret(&self) -> i128 {
+        self.secret
+    }
+
+    /// Gets the number of guesses needed to find the secret.
+    pub fn guesses(&self) -> u128 {
+        self.path.len() as u128
+    }
+
+    /// Gets the guesses made, in order.
+    pub fn path(&self) -> &[i128] {
+        &self.path
+    }
+}
+
+/// A row of the per-secret csv export.
+#[derive(Serialize)]
+struct SecretRecord {
+    /// The secret number.
+    #[serde(rename = "Secret")]
+    secret: i128,
+
+    /// The number of guesses needed.
+    #[serde(rename = "Guesses")]
+    guesses: u128,
+
+    /// The guesses made, separated by spaces.
+    #[serde(rename = "Path")]
+    path: String,
+}
+
+/// A report of how many guesses a strategy needs for every secret in a range.
+#[derive(Debug)]
+pub struct SecretReport {
+    /// Minimum the number can be.
+    min: i128,
+    /// Maximum the number can be.
+    max: i128,
+    /// The result of every secret from min to max.
+    results: Vec<SecretResult>,
+}
+
+impl SecretReport {
+    /// Plays every secret from `min` to `max` inclusive with the given strategy.
+    ///
+    /// An Err would be returned if min > max or there are more than
+    /// [`MAX_SECRETS`] secrets.
+    #[allow(clippy::result_unit_err)]
+    pub fn new(min: i128, max: i128, strategy: Box<dyn Strategy>) -> Result<Self, ()> {
+        let mut guesser = AutoNumberGuesser::with_strategy(min, max, strategy)?;
+
+        Self::from_guesser(&mut guesser)
+    }
+
+    /// Plays every secret in the range of the guesser's game.
+    ///
+    /// Every secret is played once, so an Err would be returned if there are
+    /// more than [`MAX_SECRETS`] secrets. The game's history is turned on to
+    /// get the path to each secret.
+    #[allow(clippy::result_unit_err)]
+    pub fn from_guesser(guesser: &mut AutoNumberGuesser) -> Result<Self, ()> {
+        let (min, max) = guesser.game().get_range();
+        if max.abs_diff(min) >= MAX_SECRETS {
+            return Err(());
+        }
+        let mut results = Vec::new();
+        guesser.game_mut().set_record_history(true);
+
+        for secret in min..=max {
+            guesser.game_mut().set_number(secret).unwrap();
+            guesser.solve(false);
+
+            let path = guesser.game().history().iter().map(|x| x.0).collect();
+            results.push(SecretResult { secret, path });
+        }
+
+        Ok(Self { min, max, results })
+    }
+
+    /// Gets the results of every secret, ordered from minimum to maximum.
+    pub fn results(&self) -> &[SecretResult] {
+        &self.results
+    }
+
+    /// Gets the highest number of guesses needed for any secret.
+    pub fn max_guesses(&self) -> u128 {
+        self.results.iter().map(|x| x.guesses()).max().unwrap_or(0)
+    }
+
+    /// Gets the lowest number of guesses needed for any secret.
+    pub fn min_guesses(&self) -> u128 {
+        self.results.iter().map(|x| x.guesses()).min().unwrap_or(0)
+    }
+
+    /// Gets the secrets that needed the highest number of guesses.
+    pub fn worst_case(&self) -> Vec<&SecretResult> {
+        let max = self.max_guesses();
+        self.results.iter().filter(|x| x.guesses() == max).collect()
+    }
+
+    /// Writes the report as csv with the columns "Secret", "Guesses" and "Path".
+    pub fn write_csv<W: io::Write>(&self, writer: W) -> Result<(), csv::Error> {
+        let mut writer = csv::Writer::from_writer(writer);
+
+        for result in self.results.iter() {
+            let path: Vec<String> = result.path.iter().map(|x| x.to_string()).collect();
+            writer.serialize(SecretRecord {
+                secret: result.secret,
+                guesses: result.guesses(),
+                path: path.join(" "),
+            })?;
+        }
+
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Renders the report as a number line heatmap at most `width` characters wide.
+    ///
+    /// Each character covers an equal part of the range and is shaded by the
+    /// highest number of guesses needed for a secret in that part.
+    pub fn heatmap(&self, width: usize) -> String {
+        let width = width.clamp(1, self.results.len().max(1));
+        let chunk = self.results.len().div_ceil(width).max(1);
+        let (lowest, highest) = (self.min_guesses(), self.max_guesses());
+
+        let line: String = self
+            .results
+            .chunks(chunk)
+            .map(|x| {
+                let guesses = x.iter().map(|x| x.guesses()).max().unwrap_or(0);
+                shade(guesses, lowest, highest)
+            })
+            .collect();
+        let length = line.chars().count();
+
+        let mut output = String::new();
+        // Axis labels
+        let min = self.min.to_string();
+        let max = self.max.to_string();
+        let padding = (length + 2).saturating_sub(min.len() + max.len());
+        output.push_str(&format!("{min}{}{max}\n", " ".repeat(padding)));
+        // Heatmap
+        output.push_str(&format!("|{line}|\n"));
+        // Legend
+        output.push_str(&format!(
+            "'{}' = {lowest} guesses, '{}' = {highest} guesses\n",
+            shade(lowest, lowest, highest),
+            shade(highest, lowest, highest)
+        ));
+
+        output
+    }
+
+    /// Prints the heatmap and the worst case secrets to stdout.
+    pub fn print(&self, width: usize) {
+        print!("{}", self.heatmap(width));
+
+        let worst: Vec<String> = self
+            .worst_case()
+            .iter()
+            .map(|x| x.secret.to_string())
+            .collect();
+        println!(
+            "Worst case ({} guesses): {}",
+            self.max_guesses(),
+            worst.join(", ")
+        );
+    }
+}
+
+/// Gets the heatmap character for a number of guesses between lowest and highest.
+fn shade(guesses: u128, lowest: u128, highest: u128) -> char {
+    let last = SHADES.len() - 1;
+    let index = if highest == lowest {
+        last
+    } else {
+        ((guesses - lowest) * last as u128 / (highest - lowest)) as usize
+    };
+
+    SHADES[index] as char
+}
+
+/// A review of a guess made in a game.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GuessReview {
+    /// The guess.
+    pub guess: i128,
+    /// The result of the guess.
+    pub result: Ordering,
+    /// Minimum the number could be before the guess.
+    pub min: i128,
+    /// Maximum the number could be before the guess.
+    pub max: i128,
+    /// The information gained from the guess in bits.
+    pub bits: f64,
+    /// The guess the optimal strategy would have made.
+    pub optimal: i128,
+    /// The most guesses the optimal strategy needs from here, including this one.
+    pub worst_case: u32,
+    /// The guesses the optimal strategy would have taken from here to find the
+    /// number, including this one, if the game has been won.
+    pub optimal_guesses: Option<u128>,
+}
+
+impl GuessReview {
+    /// Returns `true` if the guess was in the range the number could be.
+    pub fn is_inside(&self) -> bool {
+        (self.min..=self.max).contains(&self.guess)
+    }
+}
+
+/// Post-game analysis of every guess made in a game.
+#[derive(Debug, Clone)]
+pub struct GameReport {
+    /// The reviews of the guesses, in order.
+    reviews: Vec<GuessReview>,
+}
+
+impl GameReport {
+    /// Analyses the guesses made in a game.
+    ///
+    /// The game has to record its history for the guesses to be analysed.
+    pub fn new(game: &NumberGuessingGame) -> Self {
+        let (min, max) = game.get_range();
+
+        Self::from_history(min, max, game.history())
+    }
+
+    /// Analyses the guesses made in a game where the number is in `min..=max`.
+    pub fn from_history(min: i128, max: i128, history: &[(i128, Ordering)]) -> Self {
+        // The number is known if it has been guessed
+        let number = history.iter().find(|x| x.1 == Ordering::Equal).map(|x| x.0);
+        let mut knowledge = KnowledgeState::new(min, max);
+        let mut reviews = Vec::with_capacity(history.len());
+
+        for (guess, result) in history {
+            let Some((current_min, current_max)) = knowledge.bounds() else {
+                break;
+            };
+            let before = knowledge.size();
+            let mut strategy = Bisection::default();
+            strategy.reset(current_min, current_max);
+            let optimal = strategy.next_guess();
+            let optimal_guesses = number.map(|number| {
+                auto_guesser::play(&mut strategy, current_min, current_max, |x| x.cmp(&number))
+            });
+
+            // A contradicting answer is ignored
+            let _ = knowledge.observe(*guess, *result);
+            let after = knowledge.size();
+
+            reviews.push(GuessReview {
+                guess: *guess,
+                result: *result,
+                min: current_min,
+                max: current_max,
+                bits: efficiency::bits_gained(before, after),
+                optimal,
+                worst_case: efficiency::worst_case(before),
+                optimal_guesses,
+            });
+        }
+
+        Self { reviews }
+    }
+
+    /// Gets the reviews of the guesses, in order.
+    pub fn reviews(&self) -> &[GuessReview] {
+        &self.reviews
+    }
+
+    /// Gets the number of guesses outside the range the number could be.
+    pub fn wasted(&self) -> usize {
+        self.reviews.iter().filter(|x| !x.is_inside()).count()
+    }
+
+    /// Gets the total information gained in bits.
+    pub fn total_bits(&self) -> f64 {
+        self.reviews.iter().map(|x| x.bits).sum()
+    }
+
+    /// Writes the report as a table with a row for every guess.
+    pub fn report(&self) -> String {
+        let mut output = format!(
+            "{:>3} {:>8} {:>6} {:>17} {:>6} {:>6} {:>8} {:>12}\n",
+            "#", "Guess", "Result", "Range", "Inside", "Bits", "Optimal", "Optimal Left"
+        );
+
+        for (i, review) in self.reviews.iter().enumerate() {
+            let result = match review.result {
+                Ordering::Greater => "High",
+                Ordering::Less => "Low",
+                Ordering::Equal => "Right",
+            };
+            let left = match review.optimal_guesses {
+                Some(guesses) => format!("{guesses} (<= {})", review.worst_case),
+                None => format!("<= {}", review.worst_case),
+            };
+
+            output.push_str(&format!(
+                "{:>3} {:>8} {:>6} {:>17} {:>6} {:>6.2} {:>8} {:>12}\n",
+                i + 1,
+                review.guess,
+                result,
+                format!("{}-{}", review.min, review.max),
+                if review.is_inside() { "Yes" } else { "No" },
+                review.bits,
+                review.optimal,
+                left
+            ));
+        }
+
+        output.push_str(&format!(
+            "{} guesses, {} outside the range, {:.2} bits gained.\n",
+            self.reviews.len(),
+            self.wasted(),
+            self.total_bits()
+        ));
+        if let Some(first) = self.reviews.first().and_then(|x| x.optimal_guesses) {
+            output.push_str(&format!(
+                "The optimal strategy would have taken {first} guesses.\n"
+            ));
+        }
+
+        output
+    }
+
+    /// Prints the report to stdout.
+    pub fn print(&self) {
+        print!("{}", self.report());
+    }
+}
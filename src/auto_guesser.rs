@@ -1,7 +1,12 @@
 //! Module containing algorithm that plays number guessing game optimally.
 use std::cmp::Ordering;
 
-use super::NumberGuessingGame;
+use super::{
+    bulls_cows::{self, Score},
+    circular::{Arc, CircularStrategy, Turn},
+    knowledge::KnowledgeState,
+    NumberGuessingGame,
+};
 
 #[cfg(test)]
 mod tests {
@@ -9,27 +14,232 @@ mod tests {
 
     #[test]
     fn test_reset_n_guesses() {
+        let mut strategy = Bisection::default();
+        let mut get_guess = |min, max| {
+            strategy.reset(min, max);
+            strategy.next_guess()
+        };
+
         // Positive Numbers
-        assert_eq!(AutoNumberGuesser::get_guess(0, 10), 5);
+        assert_eq!(get_guess(0, 10), 5);
         // Negative Numbers
-        assert_eq!(AutoNumberGuesser::get_guess(-5, 5), 0);
-        assert_eq!(AutoNumberGuesser::get_guess(-11, -5), -8);
+        assert_eq!(get_guess(-5, 5), 0);
+        assert_eq!(get_guess(-11, -5), -8);
         // Rounding Down
-        assert_eq!(AutoNumberGuesser::get_guess(1, 10), 5);
+        assert_eq!(get_guess(1, 10), 5);
+        assert_eq!(get_guess(-6, -5), -6);
+        // The full range of numbers
+        assert_eq!(get_guess(i128::MIN, i128::MAX), -1);
+    }
+}
+
+/// A strategy used by the AI to pick its guesses.
+pub trait Strategy {
+    /// Prepares the strategy for a new game where the number is in `min..=max`.
+    fn reset(&mut self, min: i128, max: i128);
+
+    /// Gets the next guess to make.
+    fn next_guess(&mut self) -> i128;
+
+    /// Updates the strategy with the result of a guess.
+    fn update(&mut self, guess: i128, result: Ordering);
+}
+
+/// Bisection of the numbers left, always guessing their (rounded down) middle.
+///
+/// If no number is left, which only happens before the strategy is reset, the
+/// last guess (or the minimum of the range) is made again.
+#[derive(Debug, Default)]
+pub struct Bisection {
+    /// What the answers so far tell about the number.
+    knowledge: KnowledgeState,
+    /// The last guess made.
+    last: i128,
+}
+
+impl Bisection {
+    /// Gets what the answers so far tell about the number.
+    pub fn knowledge(&self) -> &KnowledgeState {
+        &self.knowledge
+    }
+}
+
+impl Strategy for Bisection {
+    fn reset(&mut self, min: i128, max: i128) {
+        self.knowledge = KnowledgeState::new(min, max);
+        self.last = min;
+    }
+
+    fn next_guess(&mut self) -> i128 {
+        if let Some(guess) = self.knowledge.candidates().median() {
+            self.last = guess;
+        }
+
+        self.last
+    }
+
+    fn update(&mut self, guess: i128, result: Ordering) {
+        // A contradicting answer is ignored
+        let _ = self.knowledge.observe(guess, result);
+    }
+}
+
+/// Bisection of the remaining arc for a number guessing game on a ring.
+#[derive(Debug)]
+pub struct CircularBisection {
+    /// Minimum of the ring.
+    min: i128,
+    /// Maximum of the ring.
+    max: i128,
+    /// The numbers the number can be.
+    remaining: Arc,
+}
+
+impl Default for CircularBisection {
+    fn default() -> Self {
+        Self {
+            min: 0,
+            max: 0,
+            remaining: Arc::full(0, 0),
+        }
+    }
+}
+
+impl CircularStrategy for CircularBisection {
+    fn reset(&mut self, min: i128, max: i128) {
+        self.min = min;
+        self.max = max;
+        self.remaining = Arc::full(min, max);
+    }
+
+    fn next_guess(&mut self) -> i128 {
+        // The middle of the arc, rounded down like the middle of a range
+        self.remaining.get(
+            self.min,
+            self.max,
+            self.remaining.len().saturating_sub(1) / 2,
+        )
+    }
+
+    fn update(&mut self, guess: i128, result: Turn) {
+        let answer = Arc::from_answer(self.min, self.max, guess, result);
+        self.remaining = self.remaining.intersect(&answer, self.min, self.max);
+    }
+}
+
+/// Knuth's minimax strategy for bulls and cows.
+///
+/// Every guess is the code that leaves the fewest codes in the worst case,
+/// preferring codes that can still be the answer. Every code is tried for every
+/// guess, taking O(n²) time for n codes, so this can only be used with up to
+/// [`KnuthMinimax::MAX_DIGITS`] digits.
+#[derive(Debug)]
+pub struct KnuthMinimax {
+    /// The amount of digits in the code.
+    digits: usize,
+    /// Every code that can be guessed.
+    codes: Vec<Vec<u8>>,
+    /// The codes that agree with the answers so far.
+    candidates: Vec<Vec<u8>>,
+    /// The first guess, which is the same for every game.
+    first: Option<Vec<u8>>,
+}
+
+impl KnuthMinimax {
+    /// The most digits the strategy can be used with.
+    pub const MAX_DIGITS: usize = 4;
+
+    /// Creates the strategy for codes with the amount of digits.
+    ///
+    /// An Err would be returned if there are no digits or more than
+    /// [`KnuthMinimax::MAX_DIGITS`] digits.
+    #[allow(clippy::result_unit_err)]
+    pub fn new(digits: usize, distinct: bool) -> Result<Self, ()> {
+        if !(1..=Self::MAX_DIGITS).contains(&digits) {
+            return Err(());
+        }
+        let codes = bulls_cows::codes(digits, distinct);
+
+        Ok(Self {
+            digits,
+            candidates: codes.clone(),
+            codes,
+            first: None,
+        })
+    }
+
+    /// Prepares the strategy for a new game.
+    pub fn reset(&mut self) {
+        self.candidates = self.codes.clone();
+    }
+
+    /// Gets the codes that agree with the answers so far.
+    pub fn candidates(&self) -> &[Vec<u8>] {
+        &self.candidates
+    }
+
+    /// Gets the next guess to make.
+    ///
+    /// None would be returned if no code agrees with every score so far.
+    pub fn next_guess(&mut self) -> Option<Vec<u8>> {
+        if self.candidates.len() <= 2 {
+            return self.candidates.first().cloned();
+        }
+        let first = self.candidates.len() == self.codes.len();
+        if let (true, Some(guess)) = (first, &self.first) {
+            return Some(guess.clone());
+        }
+
+        // Scores are counted at bulls * (digits + 1) + cows
+        let mut best = (usize::MAX, true, 0);
+        let mut counts = vec![0; (self.digits + 1) * (self.digits + 1)];
+        for (i, code) in self.codes.iter().enumerate() {
+            counts.fill(0);
+            for candidate in &self.candidates {
+                let score = Score::of(code, candidate);
+                counts[score.bulls * (self.digits + 1) + score.cows] += 1;
+            }
+
+            let worst = *counts.iter().max().unwrap();
+            let impossible = self.candidates.binary_search(code).is_err();
+            if (worst, impossible) < (best.0, best.1) {
+                best = (worst, impossible, i);
+            }
+        }
+
+        let guess = self.codes[best.2].clone();
+        if first {
+            self.first = Some(guess.clone());
+        }
+
+        Some(guess)
+    }
+
+    /// Updates the strategy with the score of a guess.
+    pub fn update(&mut self, guess: &[u8], score: Score) {
+        self.candidates.retain(|x| Score::of(guess, x) == score);
     }
 }
 
 /// An AI that plays the number guessing game in the optimal way.
 pub struct AutoNumberGuesser {
     game: NumberGuessingGame,
+    strategy: Box<dyn Strategy>,
 }
 
 impl AutoNumberGuesser {
     /// Creates an AI guesser for a Number Guessing Game
+    #[allow(clippy::result_unit_err)]
     pub fn new(min: i128, max: i128) -> Result<Self, ()> {
+        Self::with_strategy(min, max, Box::<Bisection>::default())
+    }
+
+    /// Creates an AI guesser for a Number Guessing Game using the given strategy.
+    #[allow(clippy::result_unit_err)]
+    pub fn with_strategy(min: i128, max: i128, strategy: Box<dyn Strategy>) -> Result<Self, ()> {
         let game = NumberGuessingGame::new(min, max)?;
 
-        Ok(Self { game })
+        Ok(Self { game, strategy })
     }
 
     /// Returns a reference to the NumberGuessingGame.
@@ -42,53 +252,32 @@ impl AutoNumberGuesser {
         &mut self.game
     }
 
+    /// Returns a mutable reference to the strategy used.
+    pub fn strategy_mut(&mut self) -> &mut dyn Strategy {
+        self.strategy.as_mut()
+    }
+
     /// Starts the number guessing algorithm.
     pub fn start(&mut self, verbose: bool) -> u128 {
         // Resetting Variables
         self.game.reset_number();
-        // Initializing initial condition
-        let mut current_min = self.game.min();
-        let mut current_max = self.game.max();
-        let mut guess = AutoNumberGuesser::get_guess(current_min, current_max);
-
-        loop {
-            // Updating Guess
-            let status = match verbose {
-                true => self.game.make_guess(guess, |res, guesses, number| {
-                    AutoNumberGuesser::handle_guess(res, guesses, number, guess)
-                }),
-                false => self.game.make_guess(guess, |_, _, _| ()),
-            };
-
-            // Changing current range
-            match status {
-                // Too Big
-                Ordering::Greater => {
-                    current_max = guess;
-                    if current_max == (current_min + 1) {
-                        current_max = current_min
-                    }
-                }
-                // Too Small
-                Ordering::Less => {
-                    current_min = guess;
-                    if current_min == (current_max - 1) {
-                        current_min = current_max;
-                    }
-                }
-                // Correct Guess
-                Ordering::Equal => break,
-            }
-
-            guess = AutoNumberGuesser::get_guess(current_min, current_max);
-        }
 
-        self.game.get_n_guesses()
+        self.solve(verbose)
     }
 
-    /// Get the next best g
-    fn get_guess(min: i128, max: i128) -> i128 {
-        (min + max) / 2
+    /// Guesses the number currently stored in the game without resetting it.
+    pub fn solve(&mut self, verbose: bool) -> u128 {
+        let (min, max) = self.game.get_range();
+        let game = &mut self.game;
+
+        play(self.strategy.as_mut(), min, max, |guess| match verbose {
+            true => game.make_guess(guess, |res, guesses, number| {
+                AutoNumberGuesser::handle_guess(res, guesses, number, guess)
+            }),
+            false => game.make_guess(guess, |_, _, _| ()),
+        });
+
+        self.game.get_n_guesses()
     }
 
     /// Guess handler function when the AI is set to verbose.
@@ -104,3 +293,30 @@ impl AutoNumberGuesser {
         }
     }
 }
+
+/// Plays a game where the number is in `min..=max` with a strategy until the number is found.
+///
+/// `make_guess` is called with every guess and returns the result of the guess.
+/// The number of guesses made is returned.
+pub fn play<F>(strategy: &mut dyn Strategy, min: i128, max: i128, mut make_guess: F) -> u128
+where
+    F: FnMut(i128) -> Ordering,
+{
+    // Initializing initial condition
+    strategy.reset(min, max);
+    let mut guesses = 0;
+
+    loop {
+        let guess = strategy.next_guess();
+        let status = make_guess(guess);
+        guesses += 1;
+
+        // Changing current range
+        strategy.update(guess, status);
+        if status == Ordering::Equal {
+            break;
+        }
+    }
+
+    guesses
+}
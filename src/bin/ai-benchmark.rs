@@ -5,9 +5,48 @@
 //! The value ranges from the arguement `min` to argument `max`,
 //! increasing each time by `step`. For each value, it will test
 //! the algorithm for `iterations` number of iterations.
+//!
+//! With `--unbounded`, the value is instead the magnitude of the number
+//! in a game without a known maximum. The galloping and Bentley–Yao
+//! strategies are tested on numbers between the previous and current value.
+//!
+//! With `--parallel`, k-ary search is tested for every k given, reporting
+//! both the number of rounds and the total number of guesses.
+//!
+//! With `--egg-drop`, the cost of bisection and the optimal egg drop strategy
+//! are compared for the given number of eggs and costs.
+//!
+//! With `--drift`, the number moves after every guess. The rate the number
+//! is found within `limit` guesses and the guesses it took are compared
+//! between bisection and the tracking strategy.
+//!
+//! With `--delay`, answers arrive a number of turns later. The guesses and
+//! latency (turns until the answer to the correct guess arrives) of the
+//! pipelined strategy are compared with bisection with instant answers.
+//!
+//! With `--group`, the given number of numbers are hidden. The queries
+//! adaptive splitting takes to find all of them are compared with the total
+//! guesses of finding each number on its own.
+//!
+//! With `--plot`, a chart of the mean and standard deviation against the
+//! maximum number (or the magnitude with `--unbounded`) is also written as
+//! an svg file.
+
+use std::fs::File;
 
 use clap::Parser;
-use guess_my_number_rs::{AutoNumberGuesser, Statistics};
+use guess_my_number_rs::{
+    auto_guesser::{Bisection, Strategy},
+    delayed::{self, DelayedGame, Pipelined},
+    drifting::{DriftTracking, DriftingGame},
+    egg_drop::{self, Costs, EggDropGame, Objective, OptimalEggDrop},
+    group::{AdaptiveSplitting, GroupGame, QueryKind},
+    parallel::{KarySearch, ParallelGuessingGame},
+    plot::{self, Series},
+    unbounded::{BentleyYao, Galloping, UnboundedGame},
+    AutoNumberGuesser, Statistics,
+};
+use rand::Rng;
 use serde_derive::Serialize;
 
 /// Command line arguments for ai-benchmark.
@@ -32,6 +71,57 @@ struct Arguments {
     /// Output csv file
     #[arg(long, short, default_value_t = String::from("data.csv"))]
     output: String,
+
+    /// Benchmark against the magnitude of the number without a known maximum.
+    #[arg(long)]
+    unbounded: bool,
+
+    /// Benchmark k-ary search with each of the given k guesses per round.
+    #[arg(long, value_delimiter = ',', conflicts_with = "unbounded")]
+    parallel: Vec<usize>,
+
+    /// Benchmark the optimal egg drop strategy against bisection.
+    #[arg(long, conflicts_with_all = ["unbounded", "parallel"])]
+    egg_drop: bool,
+
+    /// Number of eggs in the egg drop benchmark, unlimited if not given.
+    #[arg(long, requires = "egg_drop", value_parser = clap::value_parser!(u32).range(1..))]
+    eggs: Option<u32>,
+
+    /// Cost of a too low or correct guess in the egg drop benchmark.
+    #[arg(long, requires = "egg_drop", default_value_t = 1)]
+    low_cost: u128,
+
+    /// Cost of a too high guess in the egg drop benchmark.
+    #[arg(long, requires = "egg_drop", default_value_t = 1)]
+    high_cost: u128,
+
+    /// Benchmark with the number moving up to the drift after every guess.
+    #[arg(long, conflicts_with_all = ["unbounded", "parallel", "egg_drop"])]
+    drift: Option<i128>,
+
+    /// Most guesses made before giving up on a moving number.
+    #[arg(long, requires = "drift", default_value_t = 1000)]
+    limit: u128,
+
+    /// Benchmark with answers arriving the given number of turns later.
+    #[arg(long, conflicts_with_all = ["unbounded", "parallel", "egg_drop", "drift"])]
+    delay: Option<u128>,
+
+    /// Benchmark finding the given number of hidden numbers.
+    #[arg(
+        long,
+        conflicts_with_all = ["unbounded", "parallel", "egg_drop", "drift", "delay"],
+        value_parser = clap::value_parser!(u64).range(1..)
+    )]
+    group: Option<u64>,
+
+    /// Output svg file of a chart of the mean and standard deviation.
+    #[arg(
+        long,
+        conflicts_with_all = ["parallel", "egg_drop", "drift", "delay", "group"]
+    )]
+    plot: Option<String>,
 }
 
 /// Statistics to export to csv for AI benchmark.
@@ -61,6 +151,190 @@ impl BenchmarkStats {
     }
 }
 
+/// Statistics to export to csv for the unbounded benchmark.
+#[derive(Serialize)]
+pub struct UnboundedStats {
+    /// The magnitude of the number.
+    #[serde(rename = "Number Magnitude")]
+    magnitude: i128,
+
+    /// The mean of the galloping strategy.
+    #[serde(rename = "Galloping Mean")]
+    galloping_mean: f32,
+
+    /// The standard deviation of the galloping strategy.
+    #[serde(rename = "Galloping Standard Deviation")]
+    galloping_std: f32,
+
+    /// The mean of the Bentley–Yao strategy.
+    #[serde(rename = "Bentley-Yao Mean")]
+    bentley_yao_mean: f32,
+
+    /// The standard deviation of the Bentley–Yao strategy.
+    #[serde(rename = "Bentley-Yao Standard Deviation")]
+    bentley_yao_std: f32,
+}
+
+/// Statistics to export to csv for the parallel benchmark.
+#[derive(Serialize)]
+pub struct ParallelStats {
+    /// The maximum number.
+    #[serde(rename = "Max Number")]
+    max_value: i128,
+
+    /// The number of guesses each round.
+    #[serde(rename = "K")]
+    k: usize,
+
+    /// The mean number of rounds.
+    #[serde(rename = "Rounds Mean")]
+    rounds_mean: f32,
+
+    /// The standard deviation of the number of rounds.
+    #[serde(rename = "Rounds Standard Deviation")]
+    rounds_std: f32,
+
+    /// The mean number of guesses.
+    #[serde(rename = "Guesses Mean")]
+    guesses_mean: f32,
+
+    /// The standard deviation of the number of guesses.
+    #[serde(rename = "Guesses Standard Deviation")]
+    guesses_std: f32,
+}
+
+/// Statistics to export to csv for the egg drop benchmark.
+#[derive(Serialize)]
+pub struct EggDropStats {
+    /// The maximum number.
+    #[serde(rename = "Max Number")]
+    max_value: i128,
+
+    /// The mean cost of bisection when it doesn't run out of eggs.
+    #[serde(rename = "Bisection Mean")]
+    bisection_mean: f32,
+
+    /// The standard deviation of the cost of bisection.
+    #[serde(rename = "Bisection Standard Deviation")]
+    bisection_std: f32,
+
+    /// The rate bisection runs out of eggs.
+    #[serde(rename = "Bisection Failure Rate")]
+    bisection_failure: f32,
+
+    /// The mean cost of the strategy minimising the expected cost.
+    #[serde(rename = "Optimal Mean")]
+    optimal_mean: f32,
+
+    /// The standard deviation of the cost of the strategy minimising the expected cost.
+    #[serde(rename = "Optimal Standard Deviation")]
+    optimal_std: f32,
+
+    /// The expected cost of the strategy minimising the expected cost.
+    #[serde(rename = "Optimal Expected")]
+    optimal_expected: f32,
+
+    /// The worst case cost of the strategy minimising the worst case cost.
+    #[serde(rename = "Optimal Worst Case")]
+    optimal_worst: f32,
+}
+
+/// Statistics to export to csv for the drifting benchmark.
+#[derive(Serialize)]
+pub struct DriftingStats {
+    /// The maximum number.
+    #[serde(rename = "Max Number")]
+    max_value: i128,
+
+    /// The rate bisection finds the number.
+    #[serde(rename = "Bisection Capture Rate")]
+    bisection_rate: f32,
+
+    /// The mean guesses bisection takes when it finds the number.
+    #[serde(rename = "Bisection Mean")]
+    bisection_mean: f32,
+
+    /// The standard deviation of the guesses bisection takes.
+    #[serde(rename = "Bisection Standard Deviation")]
+    bisection_std: f32,
+
+    /// The rate the tracking strategy finds the number.
+    #[serde(rename = "Tracking Capture Rate")]
+    tracking_rate: f32,
+
+    /// The mean guesses the tracking strategy takes when it finds the number.
+    #[serde(rename = "Tracking Mean")]
+    tracking_mean: f32,
+
+    /// The standard deviation of the guesses the tracking strategy takes.
+    #[serde(rename = "Tracking Standard Deviation")]
+    tracking_std: f32,
+}
+
+/// Statistics to export to csv for the delayed benchmark.
+#[derive(Serialize)]
+pub struct DelayedStats {
+    /// The maximum number.
+    #[serde(rename = "Max Number")]
+    max_value: i128,
+
+    /// The mean guesses (and latency) of bisection with instant answers.
+    #[serde(rename = "Instant Mean")]
+    instant_mean: f32,
+
+    /// The standard deviation of the guesses of bisection with instant answers.
+    #[serde(rename = "Instant Standard Deviation")]
+    instant_std: f32,
+
+    /// The mean guesses of the pipelined strategy.
+    #[serde(rename = "Pipelined Guesses Mean")]
+    guesses_mean: f32,
+
+    /// The standard deviation of the guesses of the pipelined strategy.
+    #[serde(rename = "Pipelined Guesses Standard Deviation")]
+    guesses_std: f32,
+
+    /// The mean latency of the pipelined strategy.
+    #[serde(rename = "Pipelined Latency Mean")]
+    latency_mean: f32,
+
+    /// The standard deviation of the latency of the pipelined strategy.
+    #[serde(rename = "Pipelined Latency Standard Deviation")]
+    latency_std: f32,
+}
+
+/// Statistics to export to csv for the group testing benchmark.
+#[derive(Serialize)]
+pub struct GroupStats {
+    /// The maximum number.
+    #[serde(rename = "Max Number")]
+    max_value: i128,
+
+    /// The mean total guesses finding each number on its own.
+    #[serde(rename = "Independent Mean")]
+    independent_mean: f32,
+
+    /// The standard deviation of the total guesses finding each number on its own.
+    #[serde(rename = "Independent Standard Deviation")]
+    independent_std: f32,
+
+    /// The mean queries using "below" queries.
+    #[serde(rename = "Below Mean")]
+    below_mean: f32,
+
+    /// The standard deviation of the queries using "below" queries.
+    #[serde(rename = "Below Standard Deviation")]
+    below_std: f32,
+
+    /// The mean queries using "any" queries.
+    #[serde(rename = "Any Mean")]
+    any_mean: f32,
+
+    /// The standard deviation of the queries using "any" queries.
+    #[serde(rename = "Any Standard Deviation")]
+    any_std: f32,
+}
+
 fn main() {
     let args = Arguments::parse();
 
@@ -75,6 +349,41 @@ fn main() {
         std::process::exit(1);
     }
 
+    if args.iterations == 0 {
+        eprintln!("Argument `iterations` must be greater than 0.");
+        std::process::exit(1);
+    }
+
+    if args.unbounded {
+        unbounded(&args);
+        return;
+    }
+
+    if !args.parallel.is_empty() {
+        parallel(&args);
+        return;
+    }
+
+    if args.egg_drop {
+        egg_drop(&args);
+        return;
+    }
+
+    if let Some(drift) = args.drift {
+        drifting(&args, drift);
+        return;
+    }
+
+    if let Some(delay) = args.delay {
+        delayed(&args, delay);
+        return;
+    }
+
+    if let Some(k) = args.group {
+        group(&args, k as usize);
+        return;
+    }
+
     // Intializing structs.
     let mut guesser = AutoNumberGuesser::new(1, args.min).unwrap();
     let mut writer = match csv::Writer::from_path(&args.output) {
@@ -85,6 +394,8 @@ fn main() {
         }
     };
 
+    let mut series = Series::new("Bisection");
+
     // Looping over all given values
     for i in (args.min..=args.max).step_by(args.step) {
         // Setting max to current value
@@ -97,7 +408,8 @@ fn main() {
         }
 
         // Ouputting statistics
-        let stats = Statistics::from(data);
+        let stats = Statistics::try_from(data).unwrap();
+        series.push(i as f64, stats.mean() as f64, stats.std() as f64);
         let stats = BenchmarkStats::new(i, stats.mean(), stats.std());
         match writer.serialize(stats) {
             Ok(_) => (),
@@ -107,4 +419,333 @@ fn main() {
             }
         }
     }
+
+    if let Some(path) = &args.plot {
+        let svg = plot::series(&[series], "Guesses of the AI", "Max Number", "Guesses");
+        if let Err(err) = std::fs::write(path, svg) {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Benchmarks the unbounded strategies against the magnitude of the number.
+fn unbounded(args: &Arguments) {
+    let mut rng = rand::thread_rng();
+    let mut game = UnboundedGame::new(Some(1));
+    let mut writer = csv_writer(&args.output);
+    let mut series = [Series::new("Galloping"), Series::new("Bentley–Yao")];
+
+    // Looping over all given values
+    for i in (args.min..=args.max).step_by(args.step) {
+        let low = (i - args.step as i128 + 1).max(1);
+        let mut galloping = Vec::new();
+        let mut bentley_yao = Vec::new();
+
+        // Collecting data
+        for _ in 0..args.iterations {
+            game.set_number(rng.gen_range(low..=i)).unwrap();
+            galloping.push(game.solve(&mut Galloping::default()));
+            bentley_yao.push(game.solve(&mut BentleyYao::default()));
+        }
+
+        // Outputting statistics
+        let galloping = Statistics::try_from(galloping).unwrap();
+        let bentley_yao = Statistics::try_from(bentley_yao).unwrap();
+        series[0].push(i as f64, galloping.mean() as f64, galloping.std() as f64);
+        series[1].push(
+            i as f64,
+            bentley_yao.mean() as f64,
+            bentley_yao.std() as f64,
+        );
+        let stats = UnboundedStats {
+            magnitude: i,
+            galloping_mean: galloping.mean(),
+            galloping_std: galloping.std(),
+            bentley_yao_mean: bentley_yao.mean(),
+            bentley_yao_std: bentley_yao.std(),
+        };
+        write_row(&mut writer, stats);
+    }
+
+    if let Some(path) = &args.plot {
+        let svg = plot::series(&series, "Guesses of the AI", "Magnitude", "Guesses");
+        if let Err(err) = std::fs::write(path, svg) {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Benchmarks k-ary search for every k given.
+fn parallel(args: &Arguments) {
+    if args.parallel.contains(&0) {
+        eprintln!("Argument `parallel` must be greater than 0.");
+        std::process::exit(1);
+    }
+
+    let mut writer = csv_writer(&args.output);
+
+    // Looping over all given values
+    for i in (args.min..=args.max).step_by(args.step) {
+        for k in args.parallel.iter() {
+            let mut game = ParallelGuessingGame::new(1, i, *k).unwrap();
+            let mut rounds = Vec::new();
+            let mut guesses = Vec::new();
+
+            // Collecting data
+            for _ in 0..args.iterations {
+                game.reset_number();
+                rounds.push(game.solve(&mut KarySearch::default()));
+                guesses.push(game.get_n_guesses());
+            }
+
+            // Outputting statistics
+            let rounds = Statistics::try_from(rounds).unwrap();
+            let guesses = Statistics::try_from(guesses).unwrap();
+            let stats = ParallelStats {
+                max_value: i,
+                k: *k,
+                rounds_mean: rounds.mean(),
+                rounds_std: rounds.std(),
+                guesses_mean: guesses.mean(),
+                guesses_std: guesses.std(),
+            };
+            write_row(&mut writer, stats);
+        }
+    }
+}
+
+/// Benchmarks the optimal egg drop strategy against bisection.
+fn egg_drop(args: &Arguments) {
+    let mut rng = rand::thread_rng();
+    let costs = Costs::new(args.low_cost, args.high_cost);
+    let mut writer = csv_writer(&args.output);
+
+    if args.max > egg_drop::MAX_SIZE as i128 {
+        eprintln!(
+            "Argument `max` must be at most {} for the egg drop benchmark.",
+            egg_drop::MAX_SIZE
+        );
+        std::process::exit(1);
+    }
+
+    // Building the tables once for the largest range, which the smaller ranges reuse
+    let mut expected = OptimalEggDrop::new(costs, args.eggs, Objective::Expected);
+    let mut worst = OptimalEggDrop::new(costs, args.eggs, Objective::WorstCase);
+    expected.reset(1, args.max);
+    worst.reset(1, args.max);
+
+    // Looping over all given values
+    for i in (args.min..=args.max).step_by(args.step) {
+        let mut game = EggDropGame::new(1, i, costs, args.eggs).unwrap();
+        let mut bisection = Vec::new();
+        let mut failures = 0;
+        let mut optimal = Vec::new();
+
+        // Collecting data
+        for _ in 0..args.iterations {
+            let number = rng.gen_range(1..=i);
+
+            game.set_number(number).unwrap();
+            match game.solve(&mut Bisection::default()) {
+                Some(cost) => bisection.push(cost),
+                None => failures += 1,
+            }
+
+            game.set_number(number).unwrap();
+            optimal.push(game.solve(&mut expected).unwrap());
+        }
+        worst.reset(1, i);
+
+        // Outputting statistics
+        let bisection_failure = failures as f32 / args.iterations as f32;
+        // Bisection can run out of eggs every time
+        let bisection = Statistics::try_from(bisection).ok();
+        let optimal_stats = Statistics::try_from(optimal).unwrap();
+        let stats = EggDropStats {
+            max_value: i,
+            bisection_mean: bisection.as_ref().map_or(f32::NAN, Statistics::mean),
+            bisection_std: bisection.as_ref().map_or(f32::NAN, Statistics::std),
+            bisection_failure,
+            optimal_mean: optimal_stats.mean(),
+            optimal_std: optimal_stats.std(),
+            optimal_expected: expected.cost().unwrap() as f32,
+            optimal_worst: worst.cost().unwrap() as f32,
+        };
+        write_row(&mut writer, stats);
+    }
+}
+
+/// Benchmarks bisection and the tracking strategy on a moving number.
+fn drifting(args: &Arguments, drift: i128) {
+    if drift < 0 {
+        eprintln!("Argument `drift` must be atleast 0.");
+        std::process::exit(1);
+    }
+
+    let mut rng = rand::thread_rng();
+    let mut writer = csv_writer(&args.output);
+
+    // Looping over all given values
+    for i in (args.min..=args.max).step_by(args.step) {
+        let mut game = DriftingGame::new(1, i, drift).unwrap();
+        let mut bisection = Vec::new();
+        let mut tracking = Vec::new();
+
+        // Collecting data
+        for _ in 0..args.iterations {
+            let number = rng.gen_range(1..=i);
+
+            game.set_number(number).unwrap();
+            if let Some(guesses) = game.solve(&mut Bisection::default(), args.limit) {
+                bisection.push(guesses);
+            }
+
+            game.set_number(number).unwrap();
+            if let Some(guesses) = game.solve(&mut DriftTracking::new(drift), args.limit) {
+                tracking.push(guesses);
+            }
+        }
+
+        // Outputting statistics
+        let bisection_rate = bisection.len() as f32 / args.iterations as f32;
+        let tracking_rate = tracking.len() as f32 / args.iterations as f32;
+        // Neither strategy might find the number within the limit
+        let bisection = Statistics::try_from(bisection).ok();
+        let tracking = Statistics::try_from(tracking).ok();
+        let stats = DriftingStats {
+            max_value: i,
+            bisection_rate,
+            bisection_mean: bisection.as_ref().map_or(f32::NAN, Statistics::mean),
+            bisection_std: bisection.as_ref().map_or(f32::NAN, Statistics::std),
+            tracking_rate,
+            tracking_mean: tracking.as_ref().map_or(f32::NAN, Statistics::mean),
+            tracking_std: tracking.as_ref().map_or(f32::NAN, Statistics::std),
+        };
+        write_row(&mut writer, stats);
+    }
+}
+
+/// Benchmarks the pipelined strategy with delayed answers against bisection with instant answers.
+fn delayed(args: &Arguments, delay: u128) {
+    let mut rng = rand::thread_rng();
+    let mut writer = csv_writer(&args.output);
+
+    if DelayedGame::new(1, args.max, delay).is_err() {
+        eprintln!(
+            "Argument `max` must be found within {} turns for the delayed benchmark.",
+            delayed::MAX_TURNS
+        );
+        std::process::exit(1);
+    }
+
+    // Looping over all given values
+    for i in (args.min..=args.max).step_by(args.step) {
+        let mut guesser = AutoNumberGuesser::new(1, i).unwrap();
+        let mut game = DelayedGame::new(1, i, delay).unwrap();
+        let mut instant = Vec::new();
+        let mut guesses = Vec::new();
+        let mut latency = Vec::new();
+
+        // Collecting data
+        for _ in 0..args.iterations {
+            let number = rng.gen_range(1..=i);
+
+            guesser.game_mut().set_number(number).unwrap();
+            instant.push(guesser.solve(false));
+
+            game.set_number(number).unwrap();
+            latency.push(game.solve(&mut Pipelined::default()));
+            guesses.push(game.get_n_guesses());
+        }
+
+        // Outputting statistics
+        let instant = Statistics::try_from(instant).unwrap();
+        let guesses = Statistics::try_from(guesses).unwrap();
+        let latency = Statistics::try_from(latency).unwrap();
+        let stats = DelayedStats {
+            max_value: i,
+            instant_mean: instant.mean(),
+            instant_std: instant.std(),
+            guesses_mean: guesses.mean(),
+            guesses_std: guesses.std(),
+            latency_mean: latency.mean(),
+            latency_std: latency.std(),
+        };
+        write_row(&mut writer, stats);
+    }
+}
+
+/// Benchmarks adaptive splitting against finding each hidden number on its own.
+fn group(args: &Arguments, k: usize) {
+    if args.min < k as i128 {
+        eprintln!("Argument `min` must be atleast `group`.");
+        std::process::exit(1);
+    }
+
+    let mut writer = csv_writer(&args.output);
+
+    // Looping over all given values
+    for i in (args.min..=args.max).step_by(args.step) {
+        let mut guesser = AutoNumberGuesser::new(1, i).unwrap();
+        let mut game = GroupGame::new(1, i, k).unwrap();
+        let mut independent = Vec::new();
+        let mut below = Vec::new();
+        let mut any = Vec::new();
+
+        // Collecting data
+        for _ in 0..args.iterations {
+            game.reset_numbers();
+
+            let mut total = 0;
+            for number in game.numbers() {
+                guesser.game_mut().set_number(number).unwrap();
+                total += guesser.solve(false);
+            }
+            independent.push(total);
+
+            AdaptiveSplitting::new(QueryKind::Below).solve(&mut game);
+            below.push(game.get_n_queries());
+
+            let numbers = game.numbers();
+            game.set_numbers(&numbers).unwrap();
+            AdaptiveSplitting::new(QueryKind::Any).solve(&mut game);
+            any.push(game.get_n_queries());
+        }
+
+        // Outputting statistics
+        let independent = Statistics::try_from(independent).unwrap();
+        let below = Statistics::try_from(below).unwrap();
+        let any = Statistics::try_from(any).unwrap();
+        let stats = GroupStats {
+            max_value: i,
+            independent_mean: independent.mean(),
+            independent_std: independent.std(),
+            below_mean: below.mean(),
+            below_std: below.std(),
+            any_mean: any.mean(),
+            any_std: any.std(),
+        };
+        write_row(&mut writer, stats);
+    }
+}
+
+/// Creates the csv writer for the output file, exiting if it can't be created.
+fn csv_writer(path: &str) -> csv::Writer<File> {
+    match csv::Writer::from_path(path) {
+        Ok(res) => res,
+        Err(err) => {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Writes a row of statistics to the csv file, exiting if it can't be written.
+fn write_row<T: serde::Serialize>(writer: &mut csv::Writer<File>, row: T) {
+    if let Err(err) = writer.serialize(row) {
+        eprintln!("{}", err);
+        std::process::exit(1);
+    }
 }
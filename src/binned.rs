@@ -0,0 +1,276 @@
+//! Histograms of samples grouped into bins, for samples with many different values.
+//!
+//! The bins are keys of a [`Histogram`], so a binned histogram is rendered the
+//! same way as any other histogram.
+use std::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+use super::histogram::{Histogram, Sample};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quantile() {
+        let sorted = [1.0, 2.0, 3.0, 4.0, 5.0];
+        assert_eq!(quantile(&sorted, 0.0), 1.0);
+        assert_eq!(quantile(&sorted, 0.5), 3.0);
+        assert_eq!(quantile(&sorted, 0.25), 2.0);
+        assert_eq!(quantile(&sorted, 0.125), 1.5);
+        assert_eq!(quantile(&sorted, 1.0), 5.0);
+    }
+}
+
+/// The most bins a histogram can have.
+pub const MAX_BINS: usize = 10_000;
+
+/// How the bins are chosen.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Binning {
+    /// Bins of the width, starting at a multiple of the width.
+    Width(f64),
+    /// The amount of bins of the same width from the smallest to the largest sample.
+    Count(usize),
+    /// Bins from one power of the base to the next, for positive samples.
+    Log(f64),
+    /// Bins of the width `2 IQR / n^(1/3)`, by the Freedman–Diaconis rule.
+    FreedmanDiaconis,
+    /// `log2(n) + 1` bins of the same width, by Sturges' rule.
+    Sturges,
+}
+
+/// A bin of a binned histogram, from its start up to but not including its end.
+///
+/// The last bin also includes its end. Bins are only equal if their edges are
+/// too, so merging histograms with different bins keeps the bins apart.
+#[derive(Debug, Clone, Copy)]
+pub struct Bin {
+    /// The index of the bin, from the smallest bin.
+    index: usize,
+    /// The start of the bin.
+    start: f64,
+    /// The end of the bin.
+    end: f64,
+    /// Whether the bin holds whole numbers.
+    integer: bool,
+}
+
+impl Bin {
+    /// Gets the index of the bin, from the smallest bin.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Gets the start of the bin.
+    pub fn start(&self) -> f64 {
+        self.start
+    }
+
+    /// Gets the end of the bin.
+    pub fn end(&self) -> f64 {
+        self.end
+    }
+}
+
+impl PartialEq for Bin {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Bin {}
+
+impl PartialOrd for Bin {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Bin {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.index
+            .cmp(&other.index)
+            .then(self.start.total_cmp(&other.start))
+            .then(self.end.total_cmp(&other.end))
+    }
+}
+
+impl Hash for Bin {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.index.hash(state);
+        self.start.to_bits().hash(state);
+        self.end.to_bits().hash(state);
+    }
+}
+
+impl fmt::Display for Bin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Padding the whole label so bins line up like other keys
+        let label = match self.integer {
+            true if self.end - self.start <= 1.0 => format!("{}", self.start),
+            true => format!("{}..={}", self.start, self.end - 1.0),
+            false => format!("{:.2}..{:.2}", self.start, self.end),
+        };
+
+        f.pad(&label)
+    }
+}
+
+/// Builds histograms of samples grouped into bins.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bins {
+    /// How the bins are chosen.
+    binning: Binning,
+}
+
+impl Bins {
+    /// Creates a builder choosing bins by the binning.
+    pub fn new(binning: Binning) -> Self {
+        Self { binning }
+    }
+
+    /// Gets how the bins are chosen.
+    pub fn binning(&self) -> Binning {
+        self.binning
+    }
+
+    /// Gets the edges of the bins for the samples, from the smallest.
+    ///
+    /// Bin i is from `edges[i]` to `edges[i + 1]`. For integer samples, every
+    /// edge is a whole number. An Err would be returned if there are no samples,
+    /// a sample is not finite, the binning can't be used for the samples, or it
+    /// would make more than [`MAX_BINS`] bins.
+    #[allow(clippy::result_unit_err)]
+    pub fn edges<S: Sample>(&self, samples: &[S]) -> Result<Vec<f64>, ()> {
+        let mut sorted: Vec<f64> = samples.iter().map(|x| x.to_f64()).collect();
+        if sorted.is_empty() || sorted.iter().any(|x| !x.is_finite()) {
+            return Err(());
+        }
+        sorted.sort_by(f64::total_cmp);
+        let (min, max) = (sorted[0], sorted[sorted.len() - 1]);
+
+        let edges = match self.binning {
+            Binning::Width(width) => Self::width_edges(min, max, width, S::INTEGER)?,
+            Binning::Count(count) => Self::count_edges(min, max, count, S::INTEGER)?,
+            Binning::Log(base) => {
+                if min <= 0.0 || base <= 1.0 || !base.is_finite() {
+                    return Err(());
+                }
+
+                // Rounding away float errors, so powers of the base start a bin
+                let log = |x: f64| match x.log(base) {
+                    y if (y - y.round()).abs() < 1e-9 => y.round(),
+                    y => y,
+                };
+                let (first, last) = (log(min).floor(), log(max).floor() + 1.0);
+                if last - first > MAX_BINS as f64 {
+                    return Err(());
+                }
+                let (first, last) = (first as i32, last as i32);
+                let mut edges: Vec<f64> = (first..=last)
+                    .map(|x| base.powi(x))
+                    .map(|x| if S::INTEGER { x.ceil() } else { x })
+                    .collect();
+                edges.dedup();
+                edges
+            }
+            Binning::FreedmanDiaconis => {
+                let iqr = quantile(&sorted, 0.75) - quantile(&sorted, 0.25);
+                let width = 2.0 * iqr / (sorted.len() as f64).cbrt();
+
+                match width > 0.0 {
+                    true => Self::width_edges(min, max, width, S::INTEGER)?,
+                    // Too many equal samples for the rule, so Sturges' rule is used
+                    false => Self::count_edges(min, max, sturges(sorted.len()), S::INTEGER)?,
+                }
+            }
+            Binning::Sturges => Self::count_edges(min, max, sturges(sorted.len()), S::INTEGER)?,
+        };
+
+        Ok(edges)
+    }
+
+    /// Creates a histogram of the samples, with a key for every bin.
+    ///
+    /// Bins without samples have a count of 0. An Err would be returned if the
+    /// bins can't be chosen, like with [`Bins::edges`].
+    #[allow(clippy::result_unit_err)]
+    pub fn histogram<S: Sample>(&self, samples: &[S]) -> Result<Histogram<Bin>, ()> {
+        let edges = self.edges(samples)?;
+        let bins: Vec<Bin> = edges
+            .windows(2)
+            .enumerate()
+            .map(|(index, x)| Bin {
+                index,
+                start: x[0],
+                end: x[1],
+                integer: S::INTEGER,
+            })
+            .collect();
+
+        let mut histogram = Histogram::new(bins.iter().map(|x| (*x, 0)).collect());
+        for sample in samples {
+            // The last bin also holds its end
+            let index = edges.partition_point(|x| *x <= sample.to_f64());
+            let bin = bins[index.saturating_sub(1).min(bins.len() - 1)];
+            *histogram.data_mut().entry(bin).or_insert(0) += 1;
+        }
+
+        Ok(histogram)
+    }
+
+    /// Gets the edges of bins of the width, starting at a multiple of the width.
+    fn width_edges(min: f64, max: f64, width: f64, integer: bool) -> Result<Vec<f64>, ()> {
+        if width <= 0.0 || !width.is_finite() {
+            return Err(());
+        }
+        let width = if integer { width.ceil() } else { width };
+
+        let start = (min / width).floor() * width;
+        let count = ((max - start) / width).floor() + 1.0;
+        if count > MAX_BINS as f64 {
+            return Err(());
+        }
+        let count = (count as usize).max(1);
+
+        Ok((0..=count).map(|i| start + i as f64 * width).collect())
+    }
+
+    /// Gets the edges of `count` bins of the same width from min to max.
+    fn count_edges(min: f64, max: f64, count: usize, integer: bool) -> Result<Vec<f64>, ()> {
+        if count == 0 || count > MAX_BINS {
+            return Err(());
+        }
+
+        match integer {
+            // The bins hold whole numbers, so the width is rounded up
+            true => {
+                let width = ((max - min + 1.0) / count as f64).ceil().max(1.0);
+                let count = ((max - min + 1.0) / width).ceil() as usize;
+                Ok((0..=count).map(|i| min + i as f64 * width).collect())
+            }
+            false if min == max => Ok(vec![min, max + 1.0]),
+            false => {
+                let width = (max - min) / count as f64;
+                Ok((0..=count).map(|i| min + i as f64 * width).collect())
+            }
+        }
+    }
+}
+
+/// Gets the amount of bins for `n` samples by Sturges' rule.
+fn sturges(n: usize) -> usize {
+    (n as f64).log2().ceil() as usize + 1
+}
+
+/// Gets the quantile of sorted samples, interpolating between samples.
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let position = q * (sorted.len() - 1) as f64;
+    let (low, high) = (position.floor() as usize, position.ceil() as usize);
+
+    sorted[low] + (sorted[high] - sorted[low]) * (position - low as f64)
+}
@@ -0,0 +1,249 @@
+//! Number guessing game where the number is a code of digits, like bulls and cows.
+//!
+//! The answer to a guess is the amount of bulls, digits in the right place, and
+//! cows, digits in the code but in the wrong place. Codes can start with 0 and
+//! can be made to have distinct digits.
+use std::fmt;
+
+use rand::{seq::SliceRandom, Rng};
+
+use super::{auto_guesser::KnuthMinimax, input};
+
+/// The answer to a guess.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Score {
+    /// Digits in the right place.
+    pub bulls: usize,
+    /// Digits in the code but in the wrong place.
+    pub cows: usize,
+}
+
+impl Score {
+    /// Creates a score.
+    pub fn new(bulls: usize, cows: usize) -> Self {
+        Self { bulls, cows }
+    }
+
+    /// Gets the score of a guess for a code.
+    ///
+    /// Both should have the same amount of digits.
+    pub fn of(guess: &[u8], code: &[u8]) -> Self {
+        let mut bulls = 0;
+        let (mut guess_counts, mut code_counts) = ([0; 10], [0; 10]);
+
+        for (g, c) in guess.iter().zip(code) {
+            if g == c {
+                bulls += 1;
+            } else {
+                guess_counts[*g as usize] += 1;
+                code_counts[*c as usize] += 1;
+            }
+        }
+
+        let cows = guess_counts
+            .iter()
+            .zip(code_counts)
+            .map(|(g, c)| (*g).min(c))
+            .sum();
+
+        Self { bulls, cows }
+    }
+}
+
+impl fmt::Display for Score {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} bulls, {} cows", self.bulls, self.cows)
+    }
+}
+
+/// Gets every code with the amount of digits, from the smallest to the largest.
+pub fn codes(digits: usize, distinct: bool) -> Vec<Vec<u8>> {
+    let mut codes = vec![Vec::new()];
+
+    for _ in 0..digits {
+        codes = codes
+            .into_iter()
+            .flat_map(|code| {
+                (0..10).filter_map(move |digit| {
+                    if distinct && code.contains(&digit) {
+                        return None;
+                    }
+                    let mut code = code.clone();
+                    code.push(digit);
+                    Some(code)
+                })
+            })
+            .collect();
+    }
+
+    codes
+}
+
+/// Writes a code as a string of digits.
+pub fn code_to_string(code: &[u8]) -> String {
+    code.iter().map(|x| x.to_string()).collect()
+}
+
+/// A bulls and cows game.
+pub struct BullsCowsGame {
+    /// The amount of digits in the code.
+    digits: usize,
+    /// Whether every digit in the code is different.
+    distinct: bool,
+    /// The number of guesses made.
+    guesses: u128,
+    /// The current code.
+    code: Vec<u8>,
+}
+
+impl BullsCowsGame {
+    /// Creates a bulls and cows game with codes of the amount of digits.
+    ///
+    /// An Err would be returned if the amount of digits is not from 1 to 10.
+    #[allow(clippy::result_unit_err)]
+    pub fn new(digits: usize, distinct: bool) -> Result<Self, ()> {
+        if !(1..=10).contains(&digits) {
+            return Err(());
+        }
+
+        let mut output = Self {
+            digits,
+            distinct,
+            guesses: 0,
+            code: Vec::new(),
+        };
+        output.reset_number();
+
+        Ok(output)
+    }
+
+    /// Returns `true` if the code can be the code of the game.
+    pub fn is_valid(&self, code: &[u8]) -> bool {
+        let distinct = (0..code.len()).all(|i| !code[..i].contains(&code[i]));
+
+        code.len() == self.digits && code.iter().all(|x| *x < 10) && (!self.distinct || distinct)
+    }
+
+    /// Makes a guess of the code.
+    ///
+    /// An Err would be returned if the guess is not a valid code.
+    #[allow(clippy::result_unit_err)]
+    pub fn make_guess(&mut self, guess: &[u8]) -> Result<Score, ()> {
+        if !self.is_valid(guess) {
+            return Err(());
+        }
+
+        // Increasing the amount of guesses
+        self.guesses += 1;
+
+        Ok(Score::of(guess, &self.code))
+    }
+
+    /// Returns `true` if the score is for a correct guess.
+    pub fn is_correct(&self, score: Score) -> bool {
+        score.bulls == self.digits
+    }
+
+    /// Guess the code from standard input.
+    ///
+    /// `true` would be returned if the guess is correct, `false` would be returned otherwise.
+    pub fn get_guess(&mut self) -> bool {
+        loop {
+            // Getting the guess from stdin
+            let guess = input::read_line(&format!("Guess a {} digit code: ", self.digits));
+
+            // Parsing Guess
+            let guess: Option<Vec<u8>> = guess
+                .trim()
+                .chars()
+                .map(|x| x.to_digit(10).map(|x| x as u8))
+                .collect();
+            let score = match guess.map(|x| self.make_guess(&x)) {
+                Some(Ok(score)) => score,
+                _ => {
+                    println!("Invalid Code");
+                    continue;
+                }
+            };
+
+            if self.is_correct(score) {
+                println!(
+                    "Congratulations!! Your guess is correct. The code is {}. You took {} guesses.",
+                    code_to_string(&self.code),
+                    self.guesses
+                );
+                return true;
+            }
+            println!("{score}");
+
+            return false;
+        }
+    }
+
+    /// Resets the code currently stored.
+    pub fn reset_number(&mut self) {
+        let mut rng = rand::thread_rng();
+        // Resetting Code
+        self.code = match self.distinct {
+            true => {
+                let mut digits: Vec<u8> = (0..10).collect();
+                digits.shuffle(&mut rng);
+                digits.truncate(self.digits);
+                digits
+            }
+            false => (0..self.digits).map(|_| rng.gen_range(0..10)).collect(),
+        };
+        self.guesses = 0;
+    }
+
+    /// Sets the code to guess.
+    ///
+    /// An Err would be returned if the code is not valid. If this happens the
+    /// code would not change.
+    #[allow(clippy::result_unit_err)]
+    pub fn set_number(&mut self, code: &[u8]) -> Result<(), ()> {
+        if !self.is_valid(code) {
+            return Err(());
+        }
+
+        self.code = code.to_vec();
+        self.guesses = 0;
+
+        Ok(())
+    }
+
+    /// Gets the number of guesses made.
+    pub fn get_n_guesses(&self) -> u128 {
+        self.guesses
+    }
+
+    /// Gets the amount of digits in the code.
+    pub fn digits(&self) -> usize {
+        self.digits
+    }
+
+    /// Returns `true` if every digit in the code is different.
+    pub fn distinct(&self) -> bool {
+        self.distinct
+    }
+
+    /// Guesses the code currently stored using Knuth's minimax strategy.
+    ///
+    /// The number of guesses made is returned.
+    pub fn solve(&mut self, strategy: &mut KnuthMinimax) -> u128 {
+        strategy.reset();
+
+        loop {
+            // The code always agrees with its own scores
+            let guess = strategy.next_guess().unwrap();
+            let score = self.make_guess(&guess).unwrap();
+
+            strategy.update(&guess, score);
+            if self.is_correct(score) {
+                break;
+            }
+        }
+
+        self.guesses
+    }
+}
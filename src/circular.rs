@@ -0,0 +1,428 @@
+//! Number guessing game where the numbers are on a ring, like degrees or clock hours.
+//!
+//! The maximum is followed by the minimum, and the answer to a guess is which way
+//! around the ring is the shorter way to the number. When both ways are the same
+//! length the answer is clockwise.
+use std::f64::consts::TAU;
+
+use rand::Rng;
+
+use super::input;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intersect() {
+        let full = Arc::full(0, 11);
+        let arc = Arc::new(0, 11, 10, 4);
+
+        // The full ring doesn't change the arc
+        assert_eq!(full.intersect(&arc, 0, 11), arc);
+        assert_eq!(arc.intersect(&full, 0, 11), arc);
+
+        // Wrapping around the maximum
+        assert_eq!(
+            arc.intersect(&Arc::new(0, 11, 0, 6), 0, 11),
+            Arc::new(0, 11, 0, 2)
+        );
+        assert_eq!(
+            arc.intersect(&Arc::new(0, 11, 8, 3), 0, 11),
+            Arc::new(0, 11, 10, 1)
+        );
+
+        // Nothing in common
+        assert_eq!(arc.intersect(&Arc::new(0, 11, 3, 5), 0, 11).len(), 0);
+    }
+}
+
+/// The shorter way around the ring from a guess to the number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Turn {
+    /// The number is clockwise (increasing) from the guess.
+    Clockwise,
+    /// The number is counter-clockwise (decreasing) from the guess.
+    CounterClockwise,
+    /// The guess is correct.
+    Correct,
+}
+
+/// A part of the ring going clockwise from `start`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Arc {
+    /// The first number of the arc.
+    start: i128,
+    /// The amount of numbers in the arc.
+    len: u128,
+}
+
+impl Arc {
+    /// Creates an arc of `len` numbers from `start` on the ring `min..=max`.
+    pub fn new(min: i128, max: i128, start: i128, len: u128) -> Self {
+        Self {
+            start: wrap(min, max, start),
+            len: len.min(size(min, max)),
+        }
+    }
+
+    /// Creates an arc of the whole ring `min..=max`.
+    pub fn full(min: i128, max: i128) -> Self {
+        Self::new(min, max, min, size(min, max))
+    }
+
+    /// Gets the numbers the number can be after an answer to a guess on the ring `min..=max`.
+    pub fn from_answer(min: i128, max: i128, guess: i128, turn: Turn) -> Self {
+        let size = size(min, max);
+        let back = (size - 1) / 2;
+
+        match turn {
+            Turn::Clockwise => Self::new(min, max, shift(min, max, guess, 1), size / 2),
+            Turn::CounterClockwise => {
+                Self::new(min, max, shift(min, max, guess, size - back), back)
+            }
+            Turn::Correct => Self::new(min, max, guess, 1),
+        }
+    }
+
+    /// Gets the first number of the arc.
+    pub fn start(&self) -> i128 {
+        self.start
+    }
+
+    /// Gets the amount of numbers in the arc.
+    pub fn len(&self) -> u128 {
+        self.len
+    }
+
+    /// Returns `true` if there are no numbers in the arc.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Gets the number `offset` clockwise from the start of the arc on the ring `min..=max`.
+    pub fn get(&self, min: i128, max: i128, offset: u128) -> i128 {
+        shift(min, max, self.start, offset)
+    }
+
+    /// Returns `true` if the number is in the arc on the ring `min..=max`.
+    pub fn contains(&self, min: i128, max: i128, number: i128) -> bool {
+        distance(min, max, self.start, number) < self.len
+    }
+
+    /// Gets the numbers in both arcs on the ring `min..=max`.
+    ///
+    /// If the numbers in both arcs aren't next to each other, only the part
+    /// clockwise from the start of this arc is kept. This doesn't happen when the
+    /// arcs are answers from a game.
+    pub fn intersect(&self, other: &Arc, min: i128, max: i128) -> Arc {
+        let size = size(min, max);
+        if self.len == size {
+            return *other;
+        }
+
+        // Working with offsets from the start of this arc
+        let offset = distance(min, max, self.start, other.start);
+        let room = size - offset;
+        let (end, wrapped_end) = match other.len > room {
+            true => (size, other.len - room),
+            false => (offset + other.len, 0),
+        };
+        let first = (offset.min(self.len), end.min(self.len));
+        let wrapped = (0, wrapped_end.min(self.len));
+
+        let (start, end) = if wrapped.1 > 0 && wrapped.1 == first.0 && first.1 > first.0 {
+            // Joining the part wrapping around to the start of this arc
+            (0, first.1)
+        } else if wrapped.1 > 0 {
+            wrapped
+        } else {
+            first
+        };
+
+        Arc::new(min, max, self.get(min, max, start), end - start)
+    }
+}
+
+/// Gets the amount of numbers on the ring `min..=max`.
+///
+/// The ring can't be the whole i128 range, which has one more number than `u128::MAX`.
+fn size(min: i128, max: i128) -> u128 {
+    max.abs_diff(min) + 1
+}
+
+/// Gets how far the number is clockwise from the minimum, once wrapped onto the ring `min..=max`.
+fn offset(min: i128, max: i128, number: i128) -> u128 {
+    let size = size(min, max);
+    let distance = number.abs_diff(min) % size;
+
+    match number >= min || distance == 0 {
+        true => distance,
+        false => size - distance,
+    }
+}
+
+/// Gets the number `offset` clockwise from the minimum of the ring `min..=max`.
+fn at(min: i128, offset: u128) -> i128 {
+    // The offset is less than the size of the ring, so this never goes past the maximum
+    min.checked_add_unsigned(offset).unwrap()
+}
+
+/// Gets how far `to` is clockwise from `from` on the ring `min..=max`.
+fn distance(min: i128, max: i128, from: i128, to: i128) -> u128 {
+    let (from, to) = (offset(min, max, from), offset(min, max, to));
+
+    match to >= from {
+        true => to - from,
+        false => size(min, max) - (from - to),
+    }
+}
+
+/// Gets the number `by` clockwise from the number on the ring `min..=max`.
+fn shift(min: i128, max: i128, number: i128, by: u128) -> i128 {
+    let size = size(min, max);
+    let (offset, by) = (offset(min, max, number), by % size);
+
+    // Adding without going over u128::MAX
+    match offset >= size - by {
+        true => at(min, offset - (size - by)),
+        false => at(min, offset + by),
+    }
+}
+
+/// Wraps the number onto the ring `min..=max`.
+fn wrap(min: i128, max: i128, number: i128) -> i128 {
+    at(min, offset(min, max, number))
+}
+
+/// A number guessing game on a ring.
+pub struct CircularGame {
+    /// Minimum the number can be.
+    min: i128,
+    /// Maximum the number can be, followed by the minimum.
+    max: i128,
+    /// The number of guesses made.
+    guesses: u128,
+    /// The current number.
+    number: i128,
+    /// The numbers the number can be from the answers so far.
+    remaining: Arc,
+}
+
+impl CircularGame {
+    /// Creates a number guessing game on the ring `min..=max`.
+    ///
+    /// An Err would be returned if min > max or the ring is the whole i128 range.
+    #[allow(clippy::result_unit_err)]
+    pub fn new(min: i128, max: i128) -> Result<Self, ()> {
+        if min > max || max.abs_diff(min) == u128::MAX {
+            return Err(());
+        }
+
+        let mut output = Self {
+            min,
+            max,
+            guesses: 0,
+            number: min,
+            remaining: Arc::full(min, max),
+        };
+        output.reset_number();
+
+        Ok(output)
+    }
+
+    /// Gets the shorter way around the ring `min..=max` from the guess to the number.
+    pub fn turn(min: i128, max: i128, guess: i128, number: i128) -> Turn {
+        let size = size(min, max);
+        let clockwise = distance(min, max, guess, number);
+
+        if clockwise == 0 {
+            Turn::Correct
+        } else if clockwise <= size / 2 {
+            Turn::Clockwise
+        } else {
+            Turn::CounterClockwise
+        }
+    }
+
+    /// Makes a guess of the generated number.
+    ///
+    /// Guesses outside the range are wrapped onto the ring.
+    pub fn make_guess<T>(&mut self, guess: i128, guess_handler: T) -> Turn
+    where
+        T: FnOnce(Turn, u128, i128),
+    {
+        // Increasing the amount of guesses
+        self.guesses += 1;
+
+        let result = Self::turn(self.min, self.max, guess, self.number);
+        let answer = Arc::from_answer(self.min, self.max, guess, result);
+        self.remaining = self.remaining.intersect(&answer, self.min, self.max);
+        guess_handler(result, self.guesses, self.number);
+
+        result
+    }
+
+    /// Guess the number from standard input, showing the numbers left on the ring.
+    ///
+    /// `true` would be returned if the guess is correct, `false` would be returned otherwise.
+    pub fn get_guess(&mut self) -> bool {
+        // Getting the guess from stdin
+        let guess = input::read_number("Guess a number: ");
+
+        let status = self.make_guess(guess, Self::handle_guess);
+        if status != Turn::Correct {
+            print!("{}", self.render(6, Some(guess)));
+        }
+
+        status == Turn::Correct
+    }
+
+    /// Prints the result of make_guess() in a human readable form.
+    fn handle_guess(result: Turn, guesses: u128, number: i128) {
+        match result {
+            Turn::Clockwise => println!("The number is clockwise."),
+            Turn::CounterClockwise => println!("The number is counter-clockwise."),
+            Turn::Correct => println!("Congratulations!! Your guess is correct. The number is {number}. You took {guesses} guesses."),
+        }
+    }
+
+    /// Renders the ring as a circle, marking the parts where the number can be.
+    ///
+    /// The minimum is at the top and the numbers increase clockwise. Parts where
+    /// the number can be are marked with `#`, other parts with `.` and the guess
+    /// with `o`.
+    pub fn render(&self, radius: usize, guess: Option<i128>) -> String {
+        let radius = radius.max(1);
+        let (width, height) = (4 * radius + 1, 2 * radius + 1);
+        let mut grid = vec![vec![' '; width]; height];
+        let size = self.size();
+
+        // Each mark is a part of the ring, as offsets from the minimum
+        let marks = (8 * radius as u128).min(size);
+        let split = |mark: u128| size / marks * mark + size % marks * mark / marks;
+        let start = self.remaining.start();
+        for mark in 0..marks {
+            let (from, to) = (split(mark), split(mark + 1));
+            let first = at(self.min, from);
+            // The arc is in one piece, so it meets the mark if either starts in the other
+            let remaining = !self.remaining.is_empty()
+                && (self.remaining.contains(self.min, self.max, first)
+                    || distance(self.min, self.max, first, start) < to - from);
+            let symbol =
+                if guess.is_some_and(|x| (from..to).contains(&offset(self.min, self.max, x))) {
+                    'o'
+                } else if remaining {
+                    '#'
+                } else {
+                    '.'
+                };
+
+            let angle = TAU * mark as f64 / marks as f64;
+            let x = (2 * radius) as f64 * (1.0 + angle.sin());
+            let y = radius as f64 * (1.0 - angle.cos());
+            grid[y.round() as usize][x.round() as usize] = symbol;
+        }
+
+        // Labelling the minimum above the top
+        let label = self.min.to_string();
+        let mut output = format!(
+            "{}{label}\n",
+            " ".repeat((2 * radius).saturating_sub(label.len() / 2))
+        );
+        for row in grid {
+            output.push_str(row.iter().collect::<String>().trim_end());
+            output.push('\n');
+        }
+        output.push_str(&format!(
+            "The number is in the {} numbers clockwise from {}.\n",
+            self.remaining.len(),
+            self.remaining.start()
+        ));
+
+        output
+    }
+
+    /// Resets the number currently stored.
+    pub fn reset_number(&mut self) {
+        let mut rng = rand::thread_rng();
+        // Resetting Number
+        self.number = rng.gen_range(self.min..=self.max);
+        self.reset_n_guesses();
+    }
+
+    /// Sets the number to guess.
+    ///
+    /// An Err would be returned if the number is not within the range. If this
+    /// happens the number would not change.
+    #[allow(clippy::result_unit_err)]
+    pub fn set_number(&mut self, number: i128) -> Result<(), ()> {
+        if number < self.min || number > self.max {
+            return Err(());
+        }
+
+        self.number = number;
+        self.reset_n_guesses();
+
+        Ok(())
+    }
+
+    /// Resets the number of guesses made.
+    fn reset_n_guesses(&mut self) {
+        self.guesses = 0;
+        self.remaining = Arc::full(self.min, self.max);
+    }
+
+    /// Gets the number of guesses made.
+    pub fn get_n_guesses(&self) -> u128 {
+        self.guesses
+    }
+
+    /// Gets the numbers the number can be from the answers so far.
+    pub fn remaining(&self) -> Arc {
+        self.remaining
+    }
+
+    /// Gets the range of the ring.
+    ///
+    /// This function returns a tuple in the form (minimum, maximum) inclusive of the number.
+    pub fn get_range(&self) -> (i128, i128) {
+        (self.min, self.max)
+    }
+
+    /// Gets the amount of numbers on the ring.
+    pub fn size(&self) -> u128 {
+        size(self.min, self.max)
+    }
+
+    /// Guesses the number currently stored using a strategy.
+    ///
+    /// The number of guesses made is returned.
+    pub fn solve(&mut self, strategy: &mut dyn CircularStrategy) -> u128 {
+        strategy.reset(self.min, self.max);
+
+        loop {
+            let guess = strategy.next_guess();
+            let status = self.make_guess(guess, |_, _, _| ());
+
+            strategy.update(guess, status);
+            if status == Turn::Correct {
+                break;
+            }
+        }
+
+        self.guesses
+    }
+}
+
+/// A strategy for a number guessing game on a ring.
+pub trait CircularStrategy {
+    /// Prepares the strategy for a new game on the ring `min..=max`.
+    fn reset(&mut self, min: i128, max: i128);
+
+    /// Gets the next guess to make.
+    fn next_guess(&mut self) -> i128;
+
+    /// Updates the strategy with the result of a guess.
+    fn update(&mut self, guess: i128, result: Turn);
+}
@@ -0,0 +1,226 @@
+//! Number guessing game where a human and the AI take turns guessing the same number.
+//!
+//! Both see every answer, and the AI explains each of its guesses. Every guess
+//! is measured by how much it narrows the interval the number can be in, in bits
+//! of information, so the guesses of the human and the AI can be compared.
+use std::{cmp::Ordering, fmt};
+
+use super::{input, knowledge::KnowledgeState, AutoNumberGuesser};
+
+/// Who made a guess.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Guesser {
+    /// The human player.
+    Human,
+    /// The AI.
+    AI,
+}
+
+/// A guess made in a cooperative game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    /// Who made the guess.
+    pub guesser: Guesser,
+    /// The guess.
+    pub guess: i128,
+    /// The result of the guess.
+    pub result: Ordering,
+    /// The amount of numbers the number could be before the guess.
+    pub before: u128,
+    /// The amount of numbers the number can be after the guess.
+    pub after: u128,
+}
+
+impl Move {
+    /// Gets the information gained from the guess in bits.
+    pub fn bits(&self) -> f64 {
+        (self.before as f64 / self.after as f64).log2()
+    }
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result = match self.result {
+            Ordering::Greater => "too high",
+            Ordering::Less => "too low",
+            Ordering::Equal => "correct",
+        };
+
+        write!(
+            f,
+            "{:?} guessed {}, which is {result}: {} -> {} numbers, {:.2} bits gained.",
+            self.guesser,
+            self.guess,
+            self.before,
+            self.after,
+            self.bits()
+        )
+    }
+}
+
+/// A game where a human and the AI take turns guessing the same number.
+pub struct CoopGame {
+    /// The AI, which holds the game.
+    guesser: AutoNumberGuesser,
+    /// What the answers so far tell about the number.
+    knowledge: KnowledgeState,
+    /// The guesses made.
+    moves: Vec<Move>,
+}
+
+impl CoopGame {
+    /// Creates a cooperative game.
+    ///
+    /// An Err would be returned if min > max.
+    #[allow(clippy::result_unit_err)]
+    pub fn new(min: i128, max: i128) -> Result<Self, ()> {
+        let guesser = AutoNumberGuesser::new(min, max)?;
+
+        Ok(Self {
+            guesser,
+            knowledge: KnowledgeState::new(min, max),
+            moves: Vec::new(),
+        })
+    }
+
+    /// Makes a guess, narrowing the interval the number can be in.
+    pub fn make_guess(&mut self, guesser: Guesser, guess: i128) -> Move {
+        let before = self.knowledge.size();
+        let result = self.guesser.game_mut().make_guess(guess, |_, _, _| ());
+        // The answers always agree as they come from the number
+        let _ = self.knowledge.observe(guess, result);
+
+        let guess = Move {
+            guesser,
+            guess,
+            result,
+            before,
+            after: self.knowledge.size(),
+        };
+        self.moves.push(guess);
+
+        guess
+    }
+
+    /// Gets the guess the AI would make next.
+    pub fn next_guess(&mut self) -> i128 {
+        // The strategy starts from the interval left by both players
+        let (min, max) = self.get_range();
+        let strategy = self.guesser.strategy_mut();
+        strategy.reset(min, max);
+
+        strategy.next_guess()
+    }
+
+    /// Explains the guess the AI would make next.
+    pub fn explain(&mut self) -> String {
+        let guess = self.next_guess();
+        let (min, max) = self.get_range();
+        let size = self.knowledge.size();
+        let below = guess.abs_diff(min);
+        let above = max.abs_diff(guess);
+        let worst = below.max(above).max(1);
+
+        format!(
+            "The number is from {min} to {max} ({size} numbers). Guessing {guess} leaves {below} numbers below and {above} above, so at least {:.2} bits are gained.",
+            (size as f64 / worst as f64).log2()
+        )
+    }
+
+    /// Makes the guess of the AI.
+    pub fn ai_guess(&mut self) -> Move {
+        let guess = self.next_guess();
+
+        self.make_guess(Guesser::AI, guess)
+    }
+
+    /// Makes a guess from standard input for the human.
+    pub fn get_guess(&mut self) -> Move {
+        // Getting the guess from stdin
+        let guess = input::read_number("Guess a number: ");
+
+        self.make_guess(Guesser::Human, guess)
+    }
+
+    /// Returns `true` if the number has been guessed.
+    pub fn is_over(&self) -> bool {
+        self.moves
+            .last()
+            .is_some_and(|x| x.result == Ordering::Equal)
+    }
+
+    /// Gets the guesses made.
+    pub fn moves(&self) -> &[Move] {
+        &self.moves
+    }
+
+    /// Compares how well the human and the AI narrowed the interval, side by side.
+    pub fn summary(&self) -> String {
+        let stats = |guesser: Guesser| {
+            let moves: Vec<&Move> = self.moves.iter().filter(|x| x.guesser == guesser).collect();
+            let removed: u128 = moves.iter().map(|x| x.before - x.after).sum();
+            let bits: f64 = moves.iter().map(|x| x.bits()).sum();
+            let mean = match moves.len() {
+                0 => 0.0,
+                n => bits / n as f64,
+            };
+
+            (moves.len(), removed, bits, mean)
+        };
+        let (human, ai) = (stats(Guesser::Human), stats(Guesser::AI));
+
+        let mut output = format!("{:<20}{:>10}{:>10}\n", "", "Human", "AI");
+        output.push_str(&format!("{:<20}{:>10}{:>10}\n", "Guesses", human.0, ai.0));
+        output.push_str(&format!(
+            "{:<20}{:>10}{:>10}\n",
+            "Numbers Removed", human.1, ai.1
+        ));
+        output.push_str(&format!(
+            "{:<20}{:>10.2}{:>10.2}\n",
+            "Bits Gained", human.2, ai.2
+        ));
+        output.push_str(&format!(
+            "{:<20}{:>10.2}{:>10.2}\n",
+            "Bits per Guess", human.3, ai.3
+        ));
+
+        output
+    }
+
+    /// Resets the number currently stored.
+    pub fn reset_number(&mut self) {
+        self.guesser.game_mut().reset_number();
+        self.reset_moves();
+    }
+
+    /// Sets the number to guess.
+    ///
+    /// An Err would be returned if the number is not within the range. If this
+    /// happens the number would not change.
+    #[allow(clippy::result_unit_err)]
+    pub fn set_number(&mut self, number: i128) -> Result<(), ()> {
+        self.guesser.game_mut().set_number(number)?;
+        self.reset_moves();
+
+        Ok(())
+    }
+
+    /// Resets the guesses made and the interval.
+    fn reset_moves(&mut self) {
+        let (min, max) = self.guesser.game().get_range();
+        self.knowledge = KnowledgeState::new(min, max);
+        self.moves.clear();
+    }
+
+    /// Gets the interval the number can be in from the answers so far.
+    ///
+    /// This function returns a tuple in the form (minimum, maximum) inclusive of the number.
+    pub fn get_range(&self) -> (i128, i128) {
+        self.knowledge.bounds().unwrap()
+    }
+
+    /// Gets what the answers so far tell about the number.
+    pub fn knowledge(&self) -> &KnowledgeState {
+        &self.knowledge
+    }
+}
@@ -0,0 +1,299 @@
+//! Number guessing game where the answer to a guess arrives a number of turns later.
+//!
+//! Each turn at most one guess is made, and the answer to the guess made `delay`
+//! turns ago is given. The game is over when the answer to the correct guess
+//! arrives, so the latency is the number of turns played rather than guesses.
+use std::{cmp::Ordering, collections::VecDeque};
+
+use super::{knowledge::KnowledgeState, NumberGuessingGame};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capacity() {
+        // Without a delay it is bisection
+        assert_eq!(capacity(0, 0), Some(0));
+        assert_eq!(capacity(1, 0), Some(1));
+        assert_eq!(capacity(3, 0), Some(7));
+        // A delay of 1 grows like the Fibonacci numbers
+        assert_eq!(capacity(1, 1), Some(1));
+        assert_eq!(capacity(2, 1), Some(2));
+        assert_eq!(capacity(3, 1), Some(4));
+        assert_eq!(capacity(4, 1), Some(7));
+        assert_eq!(capacity(5, 1), Some(12));
+        // A long delay only finds a number a turn
+        assert_eq!(capacity(MAX_TURNS, u128::MAX), Some(MAX_TURNS));
+        assert_eq!(capacity(MAX_TURNS + 1, 0), None);
+
+        assert_eq!(capacities(7, 0), Some(vec![0, 1, 3, 7]));
+        assert_eq!(capacities(MAX_TURNS + 1, u128::MAX), None);
+    }
+}
+
+/// The most turns [`Pipelined`] plans for, which limits the range of a [`DelayedGame`].
+pub const MAX_TURNS: u128 = 10_000;
+
+/// A number guessing game where answers are delayed.
+pub struct DelayedGame {
+    /// The underlying game.
+    game: NumberGuessingGame,
+    /// The number of turns before an answer arrives.
+    delay: u128,
+    /// The number of turns played.
+    turns: u128,
+    /// Guesses waiting for their answer, with the turn the answer arrives.
+    pending: VecDeque<(u128, i128, Ordering)>,
+}
+
+impl DelayedGame {
+    /// Creates a number guessing game where the answers arrive `delay` turns later.
+    ///
+    /// An Err would be returned if min > max or the number can't always be
+    /// found within [`MAX_TURNS`] turns.
+    #[allow(clippy::result_unit_err)]
+    pub fn new(min: i128, max: i128, delay: u128) -> Result<Self, ()> {
+        let game = NumberGuessingGame::new(min, max)?;
+        capacities(max.abs_diff(min).saturating_add(1), delay).ok_or(())?;
+
+        Ok(Self {
+            game,
+            delay,
+            turns: 0,
+            pending: VecDeque::new(),
+        })
+    }
+
+    /// Plays a turn, making a guess or waiting if the guess is None.
+    ///
+    /// The guess and its answer is returned if an answer arrives this turn.
+    pub fn make_guess(&mut self, guess: Option<i128>) -> Option<(i128, Ordering)> {
+        self.turns += 1;
+
+        if let Some(guess) = guess {
+            let result = self.game.make_guess(guess, |_, _, _| ());
+            self.pending
+                .push_back((self.turns + self.delay, guess, result));
+        }
+
+        match self.pending.front() {
+            Some((turn, _, _)) if *turn <= self.turns => {
+                self.pending.pop_front().map(|x| (x.1, x.2))
+            }
+            _ => None,
+        }
+    }
+
+    /// Guesses the number currently stored using a strategy.
+    ///
+    /// The number of turns played until the answer to the correct guess arrives
+    /// is returned.
+    pub fn solve(&mut self, strategy: &mut dyn DelayedStrategy) -> u128 {
+        let (min, max) = self.game.get_range();
+        strategy.reset(min, max, self.delay);
+
+        loop {
+            let guess = strategy.next_guess();
+            if let Some((guess, result)) = self.make_guess(guess) {
+                strategy.update(guess, result);
+                if result == Ordering::Equal {
+                    break;
+                }
+            }
+        }
+
+        self.turns
+    }
+
+    /// Resets the number currently stored.
+    pub fn reset_number(&mut self) {
+        self.game.reset_number();
+        self.reset_n_turns();
+    }
+
+    /// Sets the number to guess.
+    ///
+    /// An Err would be returned if the number is not within the range. If this
+    /// happens the number would not change.
+    #[allow(clippy::result_unit_err)]
+    pub fn set_number(&mut self, number: i128) -> Result<(), ()> {
+        self.game.set_number(number)?;
+        self.reset_n_turns();
+
+        Ok(())
+    }
+
+    /// Resets the number of turns played and the answers waiting.
+    fn reset_n_turns(&mut self) {
+        self.turns = 0;
+        self.pending.clear();
+    }
+
+    /// Gets the number of turns played.
+    pub fn get_n_turns(&self) -> u128 {
+        self.turns
+    }
+
+    /// Gets the number of guesses made.
+    pub fn get_n_guesses(&self) -> u128 {
+        self.game.get_n_guesses()
+    }
+
+    /// Gets the number of turns before an answer arrives.
+    pub fn delay(&self) -> u128 {
+        self.delay
+    }
+
+    /// Returns a reference to the NumberGuessingGame.
+    pub fn game(&self) -> &NumberGuessingGame {
+        &self.game
+    }
+}
+
+/// A strategy for a game where answers are delayed.
+pub trait DelayedStrategy {
+    /// Prepares the strategy for a new game where the number is in `min..=max`
+    /// and answers arrive `delay` turns later.
+    fn reset(&mut self, min: i128, max: i128, delay: u128);
+
+    /// Gets the guess to make this turn, or None to wait.
+    fn next_guess(&mut self) -> Option<i128>;
+
+    /// Updates the strategy with an answer that arrived.
+    fn update(&mut self, guess: i128, result: Ordering);
+}
+
+/// Strategy that keeps guessing while waiting for answers.
+///
+/// Every guess assumes the answers still to arrive are too high. When an answer
+/// is too low, the guesses made since are useless and the search restarts above
+/// it. A guess splits the range so both sides are found in the same number of
+/// turns, where the side above it has `delay` fewer turns. In the worst case,
+/// the correct guess is made within the fewest turns whose [`capacity`] covers
+/// the range, which no other strategy beats.
+///
+/// The capacity of every number of turns is stored, so resetting it for a range
+/// needing more than [`MAX_TURNS`] turns panics.
+#[derive(Debug, Default)]
+pub struct Pipelined {
+    /// The number of turns before an answer arrives.
+    delay: u128,
+    /// The capacity of every number of turns, up to the turns needed for the whole range.
+    capacities: Vec<u128>,
+    /// What the answers that arrived tell about the number.
+    knowledge: KnowledgeState,
+    /// Minimum the number can be if the answers still to arrive are too high.
+    planned_min: i128,
+    /// Maximum the number can be if the answers still to arrive are too high.
+    planned_max: i128,
+    /// The correct guess, once its answer arrives.
+    found: Option<i128>,
+}
+
+impl DelayedStrategy for Pipelined {
+    fn reset(&mut self, min: i128, max: i128, delay: u128) {
+        self.delay = delay;
+        self.knowledge = KnowledgeState::new(min, max);
+        // Every planned range is within the whole range, so this covers them all
+        let size = max.abs_diff(min).saturating_add(1);
+        self.capacities = capacities(size, delay)
+            .unwrap_or_else(|| panic!("The range needs more than {MAX_TURNS} turns."));
+        self.planned_min = min;
+        self.planned_max = max;
+        self.found = None;
+    }
+
+    fn next_guess(&mut self) -> Option<i128> {
+        if self.found.is_some() || self.planned_min > self.planned_max {
+            // Waiting for answers
+            return None;
+        }
+
+        // Turns needed for the planned range
+        let size = self.planned_max.abs_diff(self.planned_min) + 1;
+        let turns = self.capacities.partition_point(|x| *x < size);
+
+        // Splitting the other numbers between both sides in proportion
+        let below = self.capacities[turns - 1];
+        let above = match (turns - 1) as u128 > self.delay {
+            true => self.capacities[turns - 1 - self.delay as usize],
+            false => 0,
+        };
+        let rest = size - 1;
+        let share = rest.saturating_mul(below) / below.saturating_add(above).max(1);
+        let below = share.clamp(rest.saturating_sub(above), below.min(rest));
+
+        let guess = self.planned_min + below as i128;
+        self.planned_max = guess - 1;
+
+        Some(guess)
+    }
+
+    fn update(&mut self, guess: i128, result: Ordering) {
+        // A contradicting answer is ignored
+        let _ = self.knowledge.observe(guess, result);
+        if result == Ordering::Equal {
+            self.found = Some(guess);
+        }
+
+        // The planned guesses are all below a too low guess
+        if let Some((min, max)) = self.knowledge.bounds() {
+            if self.planned_min < min {
+                self.planned_min = min;
+                self.planned_max = max;
+            }
+        }
+    }
+}
+
+/// Gets the most numbers where the correct guess is made within `turns` turns
+/// when answers arrive `delay` turns later, saturating at `u128::MAX`.
+///
+/// None would be returned if there are more than [`MAX_TURNS`] turns.
+pub fn capacity(turns: u128, delay: u128) -> Option<u128> {
+    if turns > MAX_TURNS {
+        return None;
+    }
+
+    let mut capacities: Vec<u128> = Vec::new();
+    while capacities.len() as u128 <= turns {
+        push_capacity(&mut capacities, delay);
+    }
+
+    Some(capacities[turns as usize])
+}
+
+/// Gets the capacity of every number of turns, up to the fewest turns covering
+/// `size` numbers.
+///
+/// None would be returned if more than [`MAX_TURNS`] turns are needed.
+fn capacities(size: u128, delay: u128) -> Option<Vec<u128>> {
+    let mut capacities = Vec::new();
+    while capacities.last().is_none_or(|x| *x < size) {
+        if capacities.len() as u128 > MAX_TURNS {
+            return None;
+        }
+        push_capacity(&mut capacities, delay);
+    }
+
+    Some(capacities)
+}
+
+/// Adds the capacity of the next number of turns, where `capacities[turns]` is
+/// the capacity of `turns` turns.
+fn push_capacity(capacities: &mut Vec<u128>, delay: u128) {
+    let turn = capacities.len();
+    let capacity = match turn {
+        0 => 0,
+        _ => {
+            let above = match (turn - 1) as u128 > delay {
+                true => capacities[turn - 1 - delay as usize],
+                false => 0,
+            };
+            capacities[turn - 1].saturating_add(above).saturating_add(1)
+        }
+    };
+
+    capacities.push(capacity);
+}
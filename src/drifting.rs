@@ -0,0 +1,209 @@
+//! Number guessing game where the number moves after every guess.
+//!
+//! After every incorrect guess, the number takes a random step of at most the
+//! drift in either direction, staying within the range. With a drift, the
+//! number isn't always found, so strategies are measured by the chance of
+//! finding the number within a number of guesses and the guesses it takes.
+use std::cmp::Ordering;
+
+use rand::Rng;
+
+use super::{auto_guesser::Strategy, input, knowledge::KnowledgeState};
+
+/// A number guessing game where the number drifts after every guess.
+pub struct DriftingGame {
+    /// Minimum the number can be.
+    min: i128,
+    /// Maximum the number can be.
+    max: i128,
+    /// Largest step the number takes after each guess.
+    drift: i128,
+    /// The number of guesses made.
+    guesses: u128,
+    /// The current number.
+    number: i128,
+}
+
+impl DriftingGame {
+    /// Creates a number guessing game where the number moves up to `drift` after every guess.
+    ///
+    /// An Err would be returned if min > max or the drift is negative.
+    #[allow(clippy::result_unit_err)]
+    pub fn new(min: i128, max: i128, drift: i128) -> Result<Self, ()> {
+        if min > max || drift < 0 {
+            return Err(());
+        }
+
+        let mut output = Self {
+            min,
+            max,
+            drift,
+            guesses: 0,
+            number: min,
+        };
+        output.reset_number();
+
+        Ok(output)
+    }
+
+    /// Makes a guess of the number.
+    ///
+    /// If the guess is incorrect, the number moves after the guess.
+    pub fn make_guess<T>(&mut self, guess: i128, guess_handler: T) -> Ordering
+    where
+        T: FnOnce(Ordering, u128, i128),
+    {
+        // Increasing the amount of guesses
+        self.guesses += 1;
+
+        let result = guess.cmp(&self.number);
+        guess_handler(result, self.guesses, self.number);
+
+        if result != Ordering::Equal {
+            self.step();
+        }
+
+        result
+    }
+
+    /// Moves the number by a random step of at most the drift.
+    fn step(&mut self) {
+        let mut rng = rand::thread_rng();
+        let step = rng.gen_range(-self.drift..=self.drift);
+
+        self.number = self.number.saturating_add(step).clamp(self.min, self.max);
+    }
+
+    /// Guess the number from standard input.
+    ///
+    /// `true` would be returned if the guess is correct, `false` would be returned otherwise.
+    pub fn get_guess(&mut self) -> bool {
+        // Getting the guess from stdin
+        let guess = input::read_number("Guess a number: ");
+
+        let status = self.make_guess(guess, Self::handle_guess);
+
+        status == Ordering::Equal
+    }
+
+    /// Prints the result of make_guess() in a human readable form.
+    fn handle_guess(result: Ordering, guesses: u128, number: i128) {
+        match result {
+            Ordering::Greater => println!("Your guess is too high. The number moved."),
+            Ordering::Less => println!("Your guess is too low. The number moved."),
+            Ordering::Equal => println!("Congratulations!! Your guess is correct. The number is {number}. You took {guesses} guesses."),
+        }
+    }
+
+    /// Guesses the number using a strategy, making at most `limit` guesses.
+    ///
+    /// The number of guesses made is returned, or None if the number isn't found.
+    pub fn solve(&mut self, strategy: &mut dyn Strategy, limit: u128) -> Option<u128> {
+        strategy.reset(self.min, self.max);
+
+        while self.guesses < limit {
+            let guess = strategy.next_guess();
+            let status = self.make_guess(guess, |_, _, _| ());
+
+            strategy.update(guess, status);
+            if status == Ordering::Equal {
+                return Some(self.guesses);
+            }
+        }
+
+        None
+    }
+
+    /// Resets the number currently stored.
+    pub fn reset_number(&mut self) {
+        let mut rng = rand::thread_rng();
+        // Resetting Number
+        self.number = rng.gen_range(self.min..=self.max);
+        self.guesses = 0;
+    }
+
+    /// Sets the number to guess.
+    ///
+    /// An Err would be returned if the number is not within the range. If this
+    /// happens the number would not change.
+    #[allow(clippy::result_unit_err)]
+    pub fn set_number(&mut self, number: i128) -> Result<(), ()> {
+        if number < self.min || number > self.max {
+            return Err(());
+        }
+
+        self.number = number;
+        self.guesses = 0;
+
+        Ok(())
+    }
+
+    /// Gets the number of guesses made.
+    pub fn get_n_guesses(&self) -> u128 {
+        self.guesses
+    }
+
+    /// Gets the largest step the number takes after each guess.
+    pub fn drift(&self) -> i128 {
+        self.drift
+    }
+
+    /// Gets the range the number can be in.
+    ///
+    /// This function returns a tuple in the form (minimum, maximum) inclusive of the number.
+    pub fn get_range(&self) -> (i128, i128) {
+        (self.min, self.max)
+    }
+}
+
+/// Bisection that widens the remaining range by the drift after every guess.
+#[derive(Debug, Default)]
+pub struct DriftTracking {
+    /// Largest step the number takes after each guess.
+    drift: i128,
+    /// Minimum of the range.
+    min: i128,
+    /// Maximum of the range.
+    max: i128,
+    /// What the answers tell about the number, widened by the drift after every guess.
+    knowledge: KnowledgeState,
+}
+
+impl DriftTracking {
+    /// Creates a tracking strategy for a number that moves up to `drift` after every guess.
+    pub fn new(drift: i128) -> Self {
+        Self {
+            drift,
+            ..Default::default()
+        }
+    }
+}
+
+impl Strategy for DriftTracking {
+    fn reset(&mut self, min: i128, max: i128) {
+        self.min = min;
+        self.max = max;
+        self.knowledge = KnowledgeState::new(min, max);
+    }
+
+    fn next_guess(&mut self) -> i128 {
+        self.knowledge.candidates().median().unwrap_or(self.min)
+    }
+
+    fn update(&mut self, guess: i128, result: Ordering) {
+        // A contradicting answer is ignored
+        let _ = self.knowledge.observe(guess, result);
+        if result == Ordering::Equal {
+            return;
+        }
+        let Some((min, max)) = self.knowledge.bounds() else {
+            return;
+        };
+
+        // The number may have moved by the drift
+        self.knowledge = KnowledgeState::new(
+            min.saturating_sub(self.drift).max(self.min),
+            max.saturating_add(self.drift).min(self.max),
+        );
+    }
+}
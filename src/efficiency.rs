@@ -0,0 +1,30 @@
+//! Measures of how much a guess tells about the number.
+//!
+//! A guess in the middle of the numbers left halves them, gaining about 1 bit of
+//! information, so the guesses of a player can be graded against it.
+
+/// Gets the most guesses needed to find a number among `size` numbers with optimal guesses.
+pub fn worst_case(size: u128) -> u32 {
+    128 - size.leading_zeros()
+}
+
+/// Gets the information gained in bits when a guess narrows `before` numbers to `after`.
+///
+/// A guess in the middle gains about 1 bit, and a correct guess gains every bit
+/// left, which can be more.
+pub fn bits_gained(before: u128, after: u128) -> f64 {
+    (before as f64 / after.max(1) as f64).log2()
+}
+
+/// Grades the bits gained by a guess compared to the 1 bit of a guess in the middle.
+///
+/// A guess is only worth grading if there was more than one number left.
+pub fn grade(bits: f64) -> char {
+    match bits {
+        x if x >= 0.9 => 'A',
+        x if x >= 0.7 => 'B',
+        x if x >= 0.5 => 'C',
+        x if x > 0.0 => 'D',
+        _ => 'F',
+    }
+}
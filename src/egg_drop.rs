@@ -0,0 +1,386 @@
+//! Number guessing game where too high guesses are more costly, like the egg drop problem.
+//!
+//! Each guess is like dropping an egg from a floor, where the number is the
+//! highest floor the egg survives. A too high guess breaks an egg, so it may
+//! cost more than a too low guess and there may only be a limited number of eggs.
+//! A correct guess costs the same as a too low guess, as the egg doesn't break.
+use std::cmp::Ordering;
+
+use super::{auto_guesser::Strategy, input, knowledge::KnowledgeState, NumberGuessingGame};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table() {
+        // One egg has to guess from the minimum
+        let mut strategy = OptimalEggDrop::new(Costs::default(), Some(1), Objective::WorstCase);
+        strategy.reset(1, 10);
+        assert_eq!(strategy.cost(), Some(10.0));
+        assert_eq!(strategy.next_guess(), 1);
+
+        // Unlimited eggs with equal costs is bisection
+        let mut strategy = OptimalEggDrop::new(Costs::default(), None, Objective::WorstCase);
+        strategy.reset(1, 7);
+        assert_eq!(strategy.cost(), Some(3.0));
+        assert_eq!(strategy.next_guess(), 4);
+
+        // Two eggs with 3 guesses finds 6 numbers
+        let mut strategy = OptimalEggDrop::new(Costs::default(), Some(2), Objective::WorstCase);
+        strategy.reset(1, 6);
+        assert_eq!(strategy.cost(), Some(3.0));
+        strategy.reset(1, 7);
+        assert_eq!(strategy.cost(), Some(4.0));
+        // The table for 7 numbers is reused
+        strategy.reset(1, 6);
+        assert_eq!(strategy.cost(), Some(3.0));
+
+        // Not built yet
+        let strategy = OptimalEggDrop::new(Costs::default(), None, Objective::Expected);
+        assert_eq!(strategy.cost(), None);
+    }
+}
+
+/// The cost of each answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Costs {
+    /// Cost of a too low or correct guess.
+    pub low: u128,
+    /// Cost of a too high guess.
+    pub high: u128,
+}
+
+impl Costs {
+    /// Creates the costs of each answer.
+    pub fn new(low: u128, high: u128) -> Self {
+        Self { low, high }
+    }
+
+    /// Gets the cost of a result.
+    pub fn cost(&self, result: Ordering) -> u128 {
+        match result {
+            Ordering::Greater => self.high,
+            _ => self.low,
+        }
+    }
+}
+
+impl Default for Costs {
+    /// Every guess costs 1.
+    fn default() -> Self {
+        Self::new(1, 1)
+    }
+}
+
+/// A number guessing game with costs for each answer and a limited number of eggs.
+///
+/// Every too high guess breaks an egg. The game is lost when the last egg is
+/// broken before the number is found.
+pub struct EggDropGame {
+    /// The underlying game.
+    game: NumberGuessingGame,
+    /// The cost of each answer.
+    costs: Costs,
+    /// The number of eggs at the start, unlimited if None.
+    eggs: Option<u32>,
+    /// The number of eggs left, unlimited if None.
+    eggs_left: Option<u32>,
+    /// The total cost of the guesses made.
+    cost: u128,
+}
+
+impl EggDropGame {
+    /// Creates an egg drop game.
+    ///
+    /// An Err would be returned if min > max, there are 0 eggs or there are more
+    /// than [`MAX_SIZE`] numbers in the range.
+    #[allow(clippy::result_unit_err)]
+    pub fn new(min: i128, max: i128, costs: Costs, eggs: Option<u32>) -> Result<Self, ()> {
+        if eggs == Some(0) || max.abs_diff(min) >= MAX_SIZE {
+            return Err(());
+        }
+
+        let game = NumberGuessingGame::new(min, max)?;
+
+        Ok(Self {
+            game,
+            costs,
+            eggs,
+            eggs_left: eggs,
+            cost: 0,
+        })
+    }
+
+    /// Makes a guess of the generated number.
+    ///
+    /// An Err would be returned if the game is lost.
+    #[allow(clippy::result_unit_err)]
+    pub fn make_guess(&mut self, guess: i128) -> Result<Ordering, ()> {
+        if self.is_lost() {
+            return Err(());
+        }
+
+        let result = self.game.make_guess(guess, |_, _, _| ());
+        self.cost += self.costs.cost(result);
+        if result == Ordering::Greater {
+            self.eggs_left = self.eggs_left.map(|x| x - 1);
+        }
+
+        Ok(result)
+    }
+
+    /// Guess the number from standard input.
+    ///
+    /// `true` would be returned if the game is over, `false` would be returned otherwise.
+    pub fn get_guess(&mut self) -> bool {
+        // Getting the guess from stdin
+        let prompt = match self.eggs_left {
+            Some(eggs) => format!("Guess a number ({eggs} eggs left): "),
+            None => "Guess a number: ".to_string(),
+        };
+        let guess = input::read_number(&prompt);
+
+        let status = self.make_guess(guess).unwrap();
+        let cost = self.cost;
+        match status {
+            Ordering::Greater => println!("Your guess is too high. The egg broke."),
+            Ordering::Less => println!("Your guess is too low."),
+            Ordering::Equal => {
+                println!("Congratulations!! Your guess is correct. It cost {cost}.");
+                return true;
+            }
+        }
+
+        if self.is_lost() {
+            println!("You ran out of eggs. It cost {cost}.");
+            return true;
+        }
+
+        false
+    }
+
+    /// Guesses the number currently stored using a strategy.
+    ///
+    /// The total cost is returned, or None if the game is lost.
+    pub fn solve(&mut self, strategy: &mut dyn Strategy) -> Option<u128> {
+        let (min, max) = self.game.get_range();
+        strategy.reset(min, max);
+
+        loop {
+            let guess = strategy.next_guess();
+            let status = self.make_guess(guess).ok()?;
+
+            strategy.update(guess, status);
+            if status == Ordering::Equal {
+                break;
+            }
+        }
+
+        Some(self.cost)
+    }
+
+    /// Resets the number currently stored, the eggs and the cost.
+    pub fn reset_number(&mut self) {
+        self.game.reset_number();
+        self.eggs_left = self.eggs;
+        self.cost = 0;
+    }
+
+    /// Sets the number to guess and resets the eggs and the cost.
+    ///
+    /// An Err would be returned if the number is not within the range. If this
+    /// happens the number would not change.
+    #[allow(clippy::result_unit_err)]
+    pub fn set_number(&mut self, number: i128) -> Result<(), ()> {
+        self.game.set_number(number)?;
+        self.eggs_left = self.eggs;
+        self.cost = 0;
+
+        Ok(())
+    }
+
+    /// Returns `true` if the last egg has been broken.
+    pub fn is_lost(&self) -> bool {
+        self.eggs_left == Some(0)
+    }
+
+    /// Gets the total cost of the guesses made.
+    pub fn cost(&self) -> u128 {
+        self.cost
+    }
+
+    /// Gets the number of eggs left, unlimited if None.
+    pub fn eggs_left(&self) -> Option<u32> {
+        self.eggs_left
+    }
+
+    /// Returns a reference to the NumberGuessingGame.
+    pub fn game(&self) -> &NumberGuessingGame {
+        &self.game
+    }
+}
+
+/// What the optimal strategy minimises.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Objective {
+    /// The highest cost of any number.
+    WorstCase,
+    /// The mean cost when every number is equally likely.
+    Expected,
+}
+
+/// The most numbers an [`EggDropGame`] or [`OptimalEggDrop`] can be used with.
+pub const MAX_SIZE: u128 = 1_000;
+
+/// Strategy that minimises the cost for the given costs and eggs using dynamic programming.
+///
+/// The table of best guesses is built for every number of numbers left and eggs
+/// left, taking O(n² × eggs) time for a range of n numbers, so it can only be
+/// used on ranges of up to [`MAX_SIZE`] numbers and resetting it for a larger
+/// range panics. The table is only rebuilt for a larger range, so smaller
+/// ranges reuse it.
+#[derive(Debug)]
+pub struct OptimalEggDrop {
+    /// The cost of each answer.
+    costs: Costs,
+    /// The number of eggs at the start, unlimited if None.
+    eggs: Option<u32>,
+    /// What is minimised.
+    objective: Objective,
+    /// The cost for n numbers with e eggs left at `values[e - 1][n]`.
+    values: Vec<Vec<f64>>,
+    /// The best guess, counting from 1, for n numbers with e eggs left at `choices[e - 1][n]`.
+    choices: Vec<Vec<usize>>,
+    /// The amount of numbers in the range of the game.
+    size: usize,
+    /// What the answers so far tell about the number.
+    knowledge: KnowledgeState,
+    /// The number of eggs left, unlimited if None.
+    eggs_left: Option<u32>,
+}
+
+impl OptimalEggDrop {
+    /// Creates an optimal strategy for the costs and eggs, unlimited if None.
+    pub fn new(costs: Costs, eggs: Option<u32>, objective: Objective) -> Self {
+        Self {
+            costs,
+            eggs,
+            objective,
+            values: Vec::new(),
+            choices: Vec::new(),
+            size: 0,
+            knowledge: KnowledgeState::default(),
+            eggs_left: eggs,
+        }
+    }
+
+    /// Gets the minimised cost for the whole range, infinite if the number can't always be found.
+    ///
+    /// This is the worst case cost or the expected cost depending on the objective.
+    /// None would be returned if the strategy hasn't been reset for a range.
+    pub fn cost(&self) -> Option<f64> {
+        let value = *self.values.last()?.get(self.size)?;
+
+        match self.objective {
+            Objective::WorstCase => Some(value),
+            Objective::Expected => Some(value / self.size as f64),
+        }
+    }
+
+    /// Builds the table of best guesses for up to `size` numbers.
+    fn build(&mut self, size: usize) {
+        // More eggs than numbers are never needed
+        let layers = match self.eggs {
+            Some(eggs) => (eggs as usize).min(size.max(1)),
+            None => 1,
+        };
+        let (low, high) = (self.costs.low as f64, self.costs.high as f64);
+
+        self.values = Vec::with_capacity(layers);
+        self.choices = Vec::with_capacity(layers);
+        for layer in 0..layers {
+            let mut values = vec![0.0; size + 1];
+            let mut choices = vec![0; size + 1];
+
+            for n in 1..=size {
+                let mut best = (f64::INFINITY, 0);
+
+                for i in 1..=n {
+                    let (below, above) = (i - 1, n - i);
+                    // The egg breaks when the number is below the guess
+                    let broken = match (below, self.eggs) {
+                        (0, _) => 0.0,
+                        (_, None) => values[below],
+                        (_, Some(_)) if layer == 0 => f64::INFINITY,
+                        (_, Some(_)) => self.values[layer - 1][below],
+                    };
+
+                    let value = match self.objective {
+                        Objective::WorstCase => {
+                            let mut value = low;
+                            if above > 0 {
+                                value = value.max(low + values[above]);
+                            }
+                            if below > 0 {
+                                value = value.max(high + broken);
+                            }
+                            value
+                        }
+                        Objective::Expected => {
+                            (above + 1) as f64 * low + values[above] + below as f64 * high + broken
+                        }
+                    };
+
+                    // Prefer guesses closer to the middle
+                    let middle = |x: usize| (2 * x).abs_diff(n + 1);
+                    if value < best.0 || (value == best.0 && middle(i) < middle(best.1)) {
+                        best = (value, i);
+                    }
+                }
+
+                values[n] = best.0;
+                choices[n] = best.1;
+            }
+
+            self.values.push(values);
+            self.choices.push(choices);
+        }
+    }
+}
+
+impl Strategy for OptimalEggDrop {
+    fn reset(&mut self, min: i128, max: i128) {
+        assert!(
+            max.abs_diff(min) < MAX_SIZE,
+            "There can be at most {MAX_SIZE} numbers in the range."
+        );
+        let size = max.abs_diff(min) as usize + 1;
+        if self.values.first().is_none_or(|x| x.len() <= size) {
+            self.build(size);
+        }
+        self.size = size;
+
+        self.knowledge = KnowledgeState::new(min, max);
+        self.eggs_left = self.eggs;
+    }
+
+    fn next_guess(&mut self) -> i128 {
+        let (min, max) = self.knowledge.bounds().unwrap_or_default();
+        let size = max.abs_diff(min) as usize + 1;
+        let layer = match self.eggs_left {
+            Some(eggs) => (eggs as usize).clamp(1, self.choices.len()),
+            None => 1,
+        };
+
+        min + self.choices[layer - 1][size] as i128 - 1
+    }
+
+    fn update(&mut self, guess: i128, result: Ordering) {
+        // A contradicting answer is ignored
+        let _ = self.knowledge.observe(guess, result);
+        if result == Ordering::Greater {
+            self.eggs_left = self.eggs_left.map(|x| x.saturating_sub(1));
+        }
+    }
+}
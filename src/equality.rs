@@ -0,0 +1,337 @@
+//! Number guessing game where a guess is only either correct or incorrect.
+//!
+//! Without knowing whether a guess is too high or too low, every strategy needs
+//! about half of the range on average, compared with about log2 of the range
+//! when guessing by bisection.
+use std::collections::HashMap;
+
+use rand::{distributions::WeightedIndex, prelude::Distribution, seq::SliceRandom};
+
+use super::{input, knowledge::KnowledgeState, NumberGuessingGame};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bisection_total() {
+        let mut cache = HashMap::new();
+        assert_eq!(bisection_total(0, &mut cache), 0);
+        assert_eq!(bisection_total(1, &mut cache), 1);
+        // 2, then 1 or 3
+        assert_eq!(bisection_total(3, &mut cache), 5);
+        // 4, then 2 or 6, then 1, 3, 5 or 7
+        assert_eq!(bisection_total(7, &mut cache), 17);
+    }
+}
+
+/// The most numbers [`RandomOrder`] and [`PriorWeighted`] should be used with,
+/// as they store every number in the range.
+pub const MAX_STORED: u128 = 10_000_000;
+
+/// A number guessing game that only tells if a guess is correct.
+pub struct EqualityGame {
+    /// The underlying game.
+    game: NumberGuessingGame,
+    /// What the guesses made tell about the number.
+    knowledge: KnowledgeState,
+}
+
+impl EqualityGame {
+    /// Creates an equality only number guessing game.
+    ///
+    /// An Err would be returned if min > max.
+    #[allow(clippy::result_unit_err)]
+    pub fn new(min: i128, max: i128) -> Result<Self, ()> {
+        let game = NumberGuessingGame::new(min, max)?;
+
+        Ok(Self {
+            game,
+            knowledge: KnowledgeState::new(min, max),
+        })
+    }
+
+    /// Makes a guess of the generated number.
+    ///
+    /// `true` would be returned if the guess is correct, `false` would be returned otherwise.
+    pub fn make_guess(&mut self, guess: i128) -> bool {
+        let status = self.game.make_guess(guess, |_, _, _| ()).is_eq();
+        // The answers always agree as they come from the number
+        let _ = self.knowledge.observe_equality(guess, status);
+
+        status
+    }
+
+    /// Guess the number from standard input.
+    ///
+    /// `true` would be returned if the guess is correct, `false` would be returned otherwise.
+    pub fn get_guess(&mut self) -> bool {
+        // Getting the guess from stdin
+        let guess = input::read_number("Guess a number: ");
+
+        let status = self.make_guess(guess);
+        match status {
+            true => println!(
+                "Congratulations!! Your guess is correct. You took {} guesses.",
+                self.game.get_n_guesses()
+            ),
+            false => println!(
+                "Your guess is wrong. The number can be {} numbers.",
+                self.knowledge.size()
+            ),
+        }
+
+        status
+    }
+
+    /// Gets what the guesses made tell about the number.
+    ///
+    /// Every wrong guess leaves a hole where the guess was.
+    pub fn knowledge(&self) -> &KnowledgeState {
+        &self.knowledge
+    }
+
+    /// Guesses the number currently stored using a strategy.
+    ///
+    /// The number of guesses made is returned.
+    pub fn solve(&mut self, strategy: &mut dyn EqualityStrategy) -> u128 {
+        let (min, max) = self.game.get_range();
+        strategy.reset(min, max);
+
+        loop {
+            let guess = strategy.next_guess();
+            let status = self.make_guess(guess);
+
+            strategy.update(guess, status);
+            if status {
+                break;
+            }
+        }
+
+        self.game.get_n_guesses()
+    }
+
+    /// Resets the number currently stored.
+    pub fn reset_number(&mut self) {
+        self.game.reset_number();
+        self.reset_knowledge();
+    }
+
+    /// Sets the number to guess.
+    ///
+    /// An Err would be returned if the number is not within the range. If this
+    /// happens the number would not change.
+    #[allow(clippy::result_unit_err)]
+    pub fn set_number(&mut self, number: i128) -> Result<(), ()> {
+        self.game.set_number(number)?;
+        self.reset_knowledge();
+
+        Ok(())
+    }
+
+    /// Resets the number to a number chosen with the given weights.
+    ///
+    /// Each weight is the relative chance of the number `min + index`. Numbers
+    /// without a weight are never chosen. An Err would be returned if the weights
+    /// are invalid, for example if all weights are 0. If this happens the number
+    /// would not change.
+    #[allow(clippy::result_unit_err)]
+    pub fn reset_number_with_prior(&mut self, weights: &[f64]) -> Result<(), ()> {
+        let (min, max) = self.game.get_range();
+        let size = max.abs_diff(min).saturating_add(1);
+        let weights = &weights[..weights.len().min(size.min(usize::MAX as u128) as usize)];
+
+        let distribution = WeightedIndex::new(weights).map_err(|_| ())?;
+        let index = distribution.sample(&mut rand::thread_rng());
+
+        self.set_number(min + index as i128)
+    }
+
+    /// Gets the number of guesses made.
+    pub fn get_n_guesses(&self) -> u128 {
+        self.game.get_n_guesses()
+    }
+
+    /// Gets the range in the form (min, max).
+    pub fn get_range(&self) -> (i128, i128) {
+        self.game.get_range()
+    }
+
+    /// Forgets the guesses made, after the number changes.
+    fn reset_knowledge(&mut self) {
+        let (min, max) = self.game.get_range();
+        self.knowledge = KnowledgeState::new(min, max);
+    }
+}
+
+/// A strategy for a game that only tells if a guess is correct.
+pub trait EqualityStrategy {
+    /// Prepares the strategy for a new game where the number is in `min..=max`.
+    fn reset(&mut self, min: i128, max: i128);
+
+    /// Gets the next guess to make.
+    fn next_guess(&mut self) -> i128;
+
+    /// Updates the strategy with whether a guess is correct.
+    fn update(&mut self, guess: i128, correct: bool);
+}
+
+/// Guesses every number from the minimum to the maximum.
+#[derive(Debug, Default)]
+pub struct Sequential {
+    /// The next number to guess.
+    next: i128,
+}
+
+impl EqualityStrategy for Sequential {
+    fn reset(&mut self, min: i128, _max: i128) {
+        self.next = min;
+    }
+
+    fn next_guess(&mut self) -> i128 {
+        self.next
+    }
+
+    fn update(&mut self, guess: i128, _correct: bool) {
+        self.next = guess.saturating_add(1);
+    }
+}
+
+/// Guesses every number in a random order, without guessing a number twice.
+///
+/// The order is stored, so this should only be used on ranges of up to
+/// [`MAX_STORED`] numbers.
+#[derive(Debug, Default)]
+pub struct RandomOrder {
+    /// The numbers not guessed yet.
+    remaining: Vec<i128>,
+}
+
+impl EqualityStrategy for RandomOrder {
+    fn reset(&mut self, min: i128, max: i128) {
+        self.remaining = (min..=max).collect();
+        self.remaining.shuffle(&mut rand::thread_rng());
+    }
+
+    fn next_guess(&mut self) -> i128 {
+        *self
+            .remaining
+            .last()
+            .expect("Every number has been guessed.")
+    }
+
+    fn update(&mut self, _guess: i128, _correct: bool) {
+        self.remaining.pop();
+    }
+}
+
+/// Guesses every number from the most to the least likely.
+///
+/// The order is stored, so this should only be used on ranges of up to
+/// [`MAX_STORED`] numbers.
+#[derive(Debug)]
+pub struct PriorWeighted {
+    /// Relative chance of the number `min + index`.
+    weights: Vec<f64>,
+    /// The range the order is for, so it is only sorted again when the range changes.
+    range: Option<(i128, i128)>,
+    /// Every number in the range, from the least to the most likely.
+    order: Vec<i128>,
+    /// The number of numbers at the start of the order not guessed yet.
+    remaining: usize,
+}
+
+impl PriorWeighted {
+    /// Creates a strategy where each weight is the relative chance of the number `min + index`.
+    ///
+    /// Numbers without a weight are guessed last.
+    pub fn new(weights: Vec<f64>) -> Self {
+        Self {
+            weights,
+            range: None,
+            order: Vec::new(),
+            remaining: 0,
+        }
+    }
+}
+
+impl EqualityStrategy for PriorWeighted {
+    fn reset(&mut self, min: i128, max: i128) {
+        if self.range != Some((min, max)) {
+            self.order = (min..=max).collect();
+
+            let weight = |x: &i128| *self.weights.get((x - min) as usize).unwrap_or(&0.0);
+            // Guessed from the end, smaller numbers are guessed first for equal weights
+            self.order.sort_by(|x, y| weight(y).total_cmp(&weight(x)));
+            self.order.reverse();
+            self.range = Some((min, max));
+        }
+
+        self.remaining = self.order.len();
+    }
+
+    fn next_guess(&mut self) -> i128 {
+        self.order[..self.remaining]
+            .last()
+            .copied()
+            .expect("Every number has been guessed.")
+    }
+
+    fn update(&mut self, _guess: i128, _correct: bool) {
+        self.remaining = self.remaining.saturating_sub(1);
+    }
+}
+
+/// Gets the expected number of guesses to guess a number in a range of `size`
+/// numbers in order, when every number is equally likely.
+///
+/// This is the same for [`Sequential`] and [`RandomOrder`].
+pub fn expected_sequential(size: u128) -> f32 {
+    (size as f32 + 1.0) / 2.0
+}
+
+/// Gets the expected number of guesses when guessing the numbers from the most
+/// to the least likely, where each weight is the relative chance of a number.
+pub fn expected_prior(weights: &[f64]) -> f32 {
+    let total: f64 = weights.iter().sum();
+    let mut weights = weights.to_vec();
+    weights.sort_by(|x, y| y.total_cmp(x));
+
+    let expected: f64 = weights
+        .iter()
+        .enumerate()
+        .map(|(i, x)| (i + 1) as f64 * x)
+        .sum();
+
+    (expected / total) as f32
+}
+
+/// Gets the expected number of guesses to guess a number in a range of `size`
+/// numbers by bisection when the guess is told to be too high or too low,
+/// when every number is equally likely.
+pub fn expected_bisection(size: u128) -> f32 {
+    if size == 0 {
+        return 0.0;
+    }
+
+    bisection_total(size, &mut HashMap::new()) as f32 / size as f32
+}
+
+/// Gets the sum of the number of guesses for every number in a range of `size`
+/// numbers when guessing by bisection.
+fn bisection_total(size: u128, cache: &mut HashMap<u128, u128>) -> u128 {
+    if size == 0 {
+        return 0;
+    }
+    if let Some(total) = cache.get(&size) {
+        return *total;
+    }
+
+    // Every number takes the middle guess, then the guesses of its half
+    let lower = (size - 1) / 2;
+    let upper = size - 1 - lower;
+    let total = size + bisection_total(lower, cache) + bisection_total(upper, cache);
+
+    cache.insert(size, total);
+    total
+}
@@ -0,0 +1,273 @@
+//! Number guessing game with several hidden numbers, found by group testing.
+//!
+//! Instead of guessing a number, each query asks how many numbers are below a
+//! value, or whether any number is in a range. The game is won by naming all of
+//! the numbers.
+use std::collections::BTreeSet;
+
+use rand::Rng;
+
+use super::input;
+
+/// A question about the hidden numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Query {
+    /// How many numbers are below the value?
+    Below(i128),
+    /// Is any number in the range, inclusive of both ends?
+    Any(i128, i128),
+}
+
+/// The kind of query used to find the numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueryKind {
+    /// [`Query::Below`]
+    Below,
+    /// [`Query::Any`]
+    Any,
+}
+
+/// A number guessing game with k distinct hidden numbers.
+pub struct GroupGame {
+    /// Minimum the numbers can be.
+    min: i128,
+    /// Maximum the numbers can be.
+    max: i128,
+    /// The number of queries made.
+    queries: u128,
+    /// The hidden numbers.
+    numbers: BTreeSet<i128>,
+    /// The amount of hidden numbers.
+    k: usize,
+}
+
+impl GroupGame {
+    /// Creates a game with k distinct numbers in `min..=max`.
+    ///
+    /// An Err would be returned if min > max, k is 0 or there are less than k
+    /// numbers in the range.
+    #[allow(clippy::result_unit_err)]
+    pub fn new(min: i128, max: i128, k: usize) -> Result<Self, ()> {
+        if min > max || k == 0 || max.abs_diff(min) < k as u128 - 1 {
+            return Err(());
+        }
+
+        let mut output = Self {
+            min,
+            max,
+            queries: 0,
+            numbers: BTreeSet::new(),
+            k,
+        };
+        output.reset_numbers();
+
+        Ok(output)
+    }
+
+    /// Makes a query about the hidden numbers.
+    ///
+    /// The answer to [`Query::Below`] is the amount of numbers below the value,
+    /// and the answer to [`Query::Any`] is 1 if any number is in the range or 0
+    /// otherwise.
+    pub fn make_query(&mut self, query: Query) -> u128 {
+        // Increasing the amount of queries
+        self.queries += 1;
+
+        match query {
+            Query::Below(x) => self.numbers.range(..x).count() as u128,
+            Query::Any(a, b) if a <= b => self.numbers.range(a..=b).next().is_some() as u128,
+            Query::Any(_, _) => 0,
+        }
+    }
+
+    /// Returns `true` if the numbers given are exactly the hidden numbers.
+    ///
+    /// The numbers can be in any order. This doesn't count as a query.
+    pub fn check(&self, numbers: &[i128]) -> bool {
+        numbers.len() == self.k
+            && numbers.iter().collect::<BTreeSet<_>>() == self.numbers.iter().collect()
+    }
+
+    /// Plays the game from standard input.
+    ///
+    /// `true` would be returned if all of the numbers are named, `false` would be
+    /// returned otherwise. The commands are `below x`, `any a b` and `answer x y ...`.
+    pub fn get_query(&mut self) -> bool {
+        loop {
+            // Getting the query from stdin
+            let query = input::read_line("Query (below x, any a b, answer x y ...): ");
+
+            // Parsing Query
+            let mut words = query.split_whitespace();
+            let command = words.next().unwrap_or("").to_ascii_lowercase();
+            let values: Result<Vec<i128>, _> = words.map(|x| x.parse::<i128>()).collect();
+            let values = match values {
+                Ok(values) => values,
+                Err(_) => {
+                    println!("Invalid Number");
+                    continue;
+                }
+            };
+
+            match (command.as_str(), values.as_slice()) {
+                ("below", [x]) => {
+                    let count = self.make_query(Query::Below(*x));
+                    println!("There are {count} numbers below {x}.");
+                }
+                ("any", [a, b]) => match self.make_query(Query::Any(*a, *b)) {
+                    0 => println!("There are no numbers from {a} to {b}."),
+                    _ => println!("There are numbers from {a} to {b}."),
+                },
+                ("answer", numbers) if self.check(numbers) => {
+                    println!(
+                        "Congratulations!! You found all {} numbers. You took {} queries.",
+                        self.k, self.queries
+                    );
+                    return true;
+                }
+                ("answer", _) => println!("Those are not the numbers."),
+                _ => {
+                    println!("Invalid Query");
+                    continue;
+                }
+            }
+
+            return false;
+        }
+    }
+
+    /// Resets the numbers currently stored.
+    pub fn reset_numbers(&mut self) {
+        let mut rng = rand::thread_rng();
+        // Resetting Numbers
+        self.numbers.clear();
+        while self.numbers.len() < self.k {
+            self.numbers.insert(rng.gen_range(self.min..=self.max));
+        }
+        self.queries = 0;
+    }
+
+    /// Sets the numbers to find.
+    ///
+    /// An Err would be returned if there aren't k numbers, a number is not within
+    /// the range or the numbers are not distinct. If this happens the numbers
+    /// would not change.
+    #[allow(clippy::result_unit_err)]
+    pub fn set_numbers(&mut self, numbers: &[i128]) -> Result<(), ()> {
+        let set: BTreeSet<i128> = numbers.iter().copied().collect();
+        if set.len() != numbers.len() || set.len() != self.k {
+            return Err(());
+        }
+        if set.iter().any(|x| *x < self.min || *x > self.max) {
+            return Err(());
+        }
+
+        self.numbers = set;
+        self.queries = 0;
+
+        Ok(())
+    }
+
+    /// Gets the number of queries made.
+    pub fn get_n_queries(&self) -> u128 {
+        self.queries
+    }
+
+    /// Gets the amount of hidden numbers.
+    pub fn k(&self) -> usize {
+        self.k
+    }
+
+    /// Gets the hidden numbers, from the smallest to the largest.
+    pub fn numbers(&self) -> Vec<i128> {
+        self.numbers.iter().copied().collect()
+    }
+
+    /// Gets the range the numbers can be in.
+    ///
+    /// This function returns a tuple in the form (minimum, maximum) inclusive of the number.
+    pub fn get_range(&self) -> (i128, i128) {
+        (self.min, self.max)
+    }
+}
+
+/// Finds every hidden number by splitting the ranges that have numbers in half.
+#[derive(Debug, Clone, Copy)]
+pub struct AdaptiveSplitting {
+    /// The kind of query used.
+    kind: QueryKind,
+}
+
+impl AdaptiveSplitting {
+    /// Creates a guesser using the given kind of query.
+    pub fn new(kind: QueryKind) -> Self {
+        Self { kind }
+    }
+
+    /// Finds the hidden numbers of a game, from the smallest to the largest.
+    pub fn solve(&self, game: &mut GroupGame) -> Vec<i128> {
+        let (min, max) = game.get_range();
+        let mut found = Vec::new();
+
+        match self.kind {
+            QueryKind::Below => Self::split_below(game, min, max, 0, game.k() as u128, &mut found),
+            QueryKind::Any => Self::split_any(game, min, max, true, &mut found),
+        }
+
+        found
+    }
+
+    /// Finds the numbers in `min..=max` given the amount below min and below max + 1.
+    fn split_below(
+        game: &mut GroupGame,
+        min: i128,
+        max: i128,
+        below_min: u128,
+        below_max: u128,
+        found: &mut Vec<i128>,
+    ) {
+        let count = below_max - below_min;
+        if count == 0 {
+            return;
+        }
+        // Every number in the range is hidden
+        if count - 1 == max.abs_diff(min) {
+            found.extend(min..=max);
+            return;
+        }
+
+        let middle = Self::middle(min, max);
+        let below_middle = game.make_query(Query::Below(middle + 1));
+        Self::split_below(game, min, middle, below_min, below_middle, found);
+        Self::split_below(game, middle + 1, max, below_middle, below_max, found);
+    }
+
+    /// Finds the numbers in `min..=max`, given if it is known to have a number.
+    fn split_any(game: &mut GroupGame, min: i128, max: i128, known: bool, found: &mut Vec<i128>) {
+        // Every number has been found
+        if found.len() == game.k() {
+            return;
+        }
+        if !known && game.make_query(Query::Any(min, max)) == 0 {
+            return;
+        }
+        if min == max {
+            found.push(min);
+            return;
+        }
+
+        let middle = Self::middle(min, max);
+        let left = game.make_query(Query::Any(min, middle)) == 1;
+        if left {
+            Self::split_any(game, min, middle, true, found);
+        }
+        // The right half has a number if the left half doesn't
+        Self::split_any(game, middle + 1, max, !left, found);
+    }
+
+    /// Gets the middle of `min..=max`, rounding down, even for the full range of numbers.
+    fn middle(min: i128, max: i128) -> i128 {
+        // The middle is always in the range
+        min.checked_add_unsigned(max.abs_diff(min) / 2).unwrap()
+    }
+}
@@ -1,17 +1,174 @@
 //! Histogram for plotting data collected from AI.
-use std::{collections::BTreeMap, fmt::Display, hash::Hash};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::{self, Display},
+    hash::Hash,
+    io,
+    ops::{Add, Sub},
+};
+
+use terminal_size::Width;
+
+/// A sample that can be averaged or put into bins.
+pub trait Sample: Copy {
+    /// Whether the samples are integers, giving bins of whole numbers.
+    const INTEGER: bool;
+
+    /// Converts the sample to a float.
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_sample {
+    ($integer:expr; $($t:ty),*) => {
+        $(
+            impl Sample for $t {
+                const INTEGER: bool = $integer;
+
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_sample!(true; u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_sample!(false; f32, f64);
 
 /// Direction of the histogram.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub enum Direction {
     /// Histogram goes up vertically.
     Vertical,
     /// Histogram goes up horizontally.
+    #[default]
     Horizontal,
 }
 
+/// What is written next to each key of the histogram.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Label {
+    /// The key and its count.
+    #[default]
+    Count,
+    /// The key and its percentage of the total count.
+    Percent,
+    /// Only the key.
+    Key,
+}
+
+/// How the bars of the histogram are scaled.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Scale {
+    /// One `*` for every count.
+    #[default]
+    Unit,
+    /// Bars of block characters scaled to fit the maximum width or height,
+    /// with a legend showing the scale.
+    Fit,
+    /// One `*` for every count if the bars fit, scaled to fit otherwise.
+    Auto,
+}
+
+/// The blocks for eighths of a horizontal bar, from 1 to 8 eighths.
+const HORIZONTAL_BLOCKS: [char; 8] = ['▏', '▎', '▍', '▌', '▋', '▊', '▉', '█'];
+
+/// The blocks for eighths of a vertical bar, from 1 to 8 eighths.
+const VERTICAL_BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// The height of a vertical histogram if no maximum height is set.
+pub const DEFAULT_HEIGHT: usize = 20;
+
+/// Gets the width of the terminal.
+///
+/// If standard output isn't a terminal, the `COLUMNS` environment variable is
+/// used, or 80 if it isn't set.
+pub fn terminal_width() -> usize {
+    if let Some((Width(width), _)) = terminal_size::terminal_size() {
+        return width as usize;
+    }
+
+    std::env::var("COLUMNS")
+        .ok()
+        .and_then(|x| x.parse().ok())
+        .unwrap_or(80)
+}
+
+/// Options for rendering a histogram.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Options {
+    /// The direction of the histogram.
+    pub direction: Direction,
+    /// The width of each key and label.
+    pub width: usize,
+    /// What is written next to each key.
+    pub label: Label,
+    /// How the bars are scaled.
+    pub scale: Scale,
+    /// The most characters in a line of a horizontal histogram, or the width
+    /// of the terminal if None.
+    pub max_width: Option<usize>,
+    /// The most rows of bars in a vertical histogram, or [`DEFAULT_HEIGHT`] if None.
+    pub max_height: Option<usize>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            direction: Direction::default(),
+            width: 5,
+            label: Label::default(),
+            scale: Scale::default(),
+            max_width: None,
+            max_height: None,
+        }
+    }
+}
+
+impl Options {
+    /// Creates the default options going in the direction.
+    pub fn new(direction: Direction) -> Self {
+        Self {
+            direction,
+            ..Self::default()
+        }
+    }
+
+    /// Gets the count for each character of a bar, or None for one `*` for every count.
+    ///
+    /// `room` is the most characters a bar can have.
+    fn unit(&self, highest: u128, room: usize) -> Option<u128> {
+        let room = room.max(1) as u128;
+
+        match self.scale {
+            Scale::Unit => None,
+            Scale::Auto if highest <= room => None,
+            _ => Some(highest.div_ceil(room).max(1)),
+        }
+    }
+}
+
+/// Gets a bar of `eighths` eighths of a character from the blocks.
+fn bar(eighths: u128, blocks: &[char; 8]) -> String {
+    let mut output: String = std::iter::repeat_n(blocks[7], (eighths / 8) as usize).collect();
+    match eighths % 8 {
+        0 => (),
+        x => output.push(blocks[x as usize - 1]),
+    }
+
+    output
+}
+
+/// Gets the length of a bar for a count in eighths of a character.
+fn eighths(value: u128, unit: u128) -> u128 {
+    (value * 8 + unit / 2) / unit
+}
+
 /// A structure that represents a Histogram.
 pub struct Histogram<T: Eq + Hash> {
     data: BTreeMap<T, u128>,
+    /// The options used when the histogram is displayed.
+    options: Options,
 }
 
 impl<T: Eq + Hash + Display + Ord> Histogram<T> {
@@ -20,77 +177,227 @@ impl<T: Eq + Hash + Display + Ord> Histogram<T> {
     /// Each of the entry represents the number of occurances
     /// of the key.
     pub fn new(data: BTreeMap<T, u128>) -> Self {
-        Self { data }
+        Self {
+            data,
+            options: Options::default(),
+        }
     }
 
     /// Constructs a new Histogram from a vector of data.
     pub fn from_vec(data: Vec<T>) -> Self {
-        let mut map = BTreeMap::<T, u128>::new();
-        for i in data {
-            let stat = map.entry(i).or_insert(0);
-            *stat += 1;
-        }
-
-        Self::new(map)
+        data.into_iter().collect()
     }
 
     /// Prints the histogram to stdout.
     pub fn print(&self, direction: Direction) {
-        match direction {
-            Direction::Horizontal => self.print_horizontal(),
-            Direction::Vertical => self.print_vertical(),
+        self.write(&mut io::stdout(), &Options::new(direction))
+            .expect("Failed to write to stdout.");
+    }
+
+    /// Writes the histogram to a writer, such as a file or stdout.
+    pub fn write<W: io::Write>(&self, writer: &mut W, options: &Options) -> io::Result<()> {
+        let mut output = String::new();
+        self.render(&mut output, options)
+            .expect("Writing to a String never fails.");
+
+        writer.write_all(output.as_bytes())
+    }
+
+    /// Renders the histogram to a formatter or a String.
+    ///
+    /// An empty histogram is rendered as "No data."
+    pub fn render<W: fmt::Write>(&self, f: &mut W, options: &Options) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "No data.");
+        }
+
+        match options.direction {
+            Direction::Horizontal => self.render_horizontal(f, options),
+            Direction::Vertical => self.render_vertical(f, options),
         }
     }
 
-    /// Prints a horizontal histogram to stdout.
-    fn print_horizontal(&self) {
+    /// Renders a horizontal histogram.
+    fn render_horizontal<W: fmt::Write>(&self, f: &mut W, options: &Options) -> fmt::Result {
+        let width = options.width;
+        let highest = self.get_highest().map_or(0, |x| *x.1);
+        let label_width = match options.label {
+            Label::Key => width + 1,
+            _ => 2 * width + 1,
+        };
+        let max_width = options.max_width.unwrap_or_else(terminal_width);
+        let unit = options.unit(highest, max_width.saturating_sub(label_width));
+
         for (key, value) in self.data.iter() {
-            print!("{key:>5}/{value:<5}");
+            match options.label {
+                Label::Key => write!(f, "{key:>width$} ")?,
+                _ => write!(f, "{key:>width$}/{:<width$}", self.label(*value, options))?,
+            }
 
-            for _ in 0..*value {
-                print!("*");
+            match unit {
+                Some(unit) => write!(f, "{}", bar(eighths(*value, unit), &HORIZONTAL_BLOCKS))?,
+                None => {
+                    for _ in 0..*value {
+                        write!(f, "*")?;
+                    }
+                }
             }
-            println!();
+            writeln!(f)?;
         }
+
+        if let Some(unit) = unit {
+            Self::render_legend(f, unit, HORIZONTAL_BLOCKS[7])?;
+        }
+
+        Ok(())
     }
 
-    /// Prints a vertical histogram to stdout.
-    fn print_vertical(&self) {
-        let height = *self.get_highest().1;
+    /// Renders a vertical histogram.
+    fn render_vertical<W: fmt::Write>(&self, f: &mut W, options: &Options) -> fmt::Result {
+        let width = options.width;
+        let highest = self.get_highest().map_or(0, |x| *x.1);
+        let unit = options.unit(highest, options.max_height.unwrap_or(DEFAULT_HEIGHT));
 
-        // Printing stars
-        for i in (0..height).rev() {
-            // Looping over all keys to check if the count is greater
-            for value in self.data.values() {
-                if *value > i {
-                    print!("{:^5}", "*")
-                } else {
-                    print!("{:^5}", " ")
+        // Printing bars
+        match unit {
+            Some(unit) => {
+                let height = eighths(highest, unit).div_ceil(8);
+
+                for i in (0..height).rev() {
+                    for value in self.data.values() {
+                        // The eighths of the bar in this row
+                        let cell = eighths(*value, unit).saturating_sub(i * 8).min(8);
+                        match cell {
+                            0 => write!(f, "{:^width$}", " ")?,
+                            x => write!(f, "{:^width$}", VERTICAL_BLOCKS[x as usize - 1])?,
+                        }
+                    }
+                    writeln!(f)?
+                }
+            }
+            None => {
+                for i in (0..highest).rev() {
+                    // Looping over all keys to check if the count is greater
+                    for value in self.data.values() {
+                        if *value > i {
+                            write!(f, "{:^width$}", "*")?
+                        } else {
+                            write!(f, "{:^width$}", " ")?
+                        }
+                    }
+                    writeln!(f)?
                 }
             }
-            println!()
         }
 
         // Printing labels
         for key in self.data.keys() {
-            print!("{:^5}", key)
+            write!(f, "{key:^width$}")?
         }
-        println!();
+        writeln!(f)?;
         // Printing Value
-        for value in self.data.values() {
-            print!("{:^5}", value)
+        if options.label != Label::Key {
+            for value in self.data.values() {
+                write!(f, "{:^width$}", self.label(*value, options))?
+            }
+            writeln!(f)?;
+        }
+
+        if let Some(unit) = unit {
+            Self::render_legend(f, unit, VERTICAL_BLOCKS[7])?;
+        }
+
+        Ok(())
+    }
+
+    /// Renders the legend showing the count of a full block.
+    fn render_legend<W: fmt::Write>(f: &mut W, unit: u128, block: char) -> fmt::Result {
+        writeln!(f, "Scale: {block} = {unit} counts")
+    }
+
+    /// Renders the empirical cumulative distribution, the fraction of the
+    /// total count at or before each key, as a bar of the full width.
+    ///
+    /// An empty histogram is rendered as "No data."
+    pub fn render_ecdf<W: fmt::Write>(&self, f: &mut W, options: &Options) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "No data.");
+        }
+
+        let width = options.width;
+        let total = self.total().max(1);
+        let max_width = options.max_width.unwrap_or_else(terminal_width);
+        // The percentage takes up to 6 characters, like "100.0%", and a space
+        let room = max_width.saturating_sub(width + 8).max(1) as u128;
+
+        for (key, sum) in self.cumulative() {
+            let percent = format!("{:.1}%", 100.0 * sum as f64 / total as f64);
+            // Rounding to the nearest eighth of a character
+            let eighths = (sum * room * 8 + total / 2) / total;
+            writeln!(
+                f,
+                "{key:>width$}/{percent:<7}{}",
+                bar(eighths, &HORIZONTAL_BLOCKS)
+            )?;
         }
-        println!();
+
+        Ok(())
+    }
+
+    /// Gets a line of a block for each key, as high as its count.
+    ///
+    /// Keys without a count are a space. Only keys in the histogram get a block,
+    /// so keys missing between them leave no gap. Keys that should show a gap
+    /// have to be added with a count of 0.
+    pub fn sparkline(&self) -> String {
+        let highest = self.get_highest().map_or(0, |x| *x.1).max(1);
+
+        self.data
+            .values()
+            .map(|value| match (value * 8).div_ceil(highest) {
+                0 => ' ',
+                x => VERTICAL_BLOCKS[x as usize - 1],
+            })
+            .collect()
+    }
+
+    /// Gets the label for a count.
+    fn label(&self, value: u128, options: &Options) -> String {
+        match options.label {
+            Label::Percent => {
+                format!("{:.1}%", 100.0 * value as f64 / self.total().max(1) as f64)
+            }
+            _ => value.to_string(),
+        }
+    }
+
+    /// Gets the options used when the histogram is displayed.
+    pub fn options(&self) -> &Options {
+        &self.options
+    }
+
+    /// Sets the options used when the histogram is displayed.
+    pub fn set_options(&mut self, options: Options) {
+        self.options = options;
     }
 
     /// Gets the current entry with the highest count.
-    pub fn get_highest(&self) -> (&T, &u128) {
-        self.data.iter().max_by(|x, y| x.1.cmp(y.1)).unwrap()
+    ///
+    /// None would be returned if the histogram is empty.
+    pub fn get_highest(&self) -> Option<(&T, &u128)> {
+        self.data.iter().max_by(|x, y| x.1.cmp(y.1))
     }
 
     /// Gets the current entry with the lowest count.
-    pub fn get_lowest(&self) -> (&T, &u128) {
-        self.data.iter().min_by(|x, y| x.1.cmp(y.1)).unwrap()
+    ///
+    /// None would be returned if the histogram is empty.
+    pub fn get_lowest(&self) -> Option<(&T, &u128)> {
+        self.data.iter().min_by(|x, y| x.1.cmp(y.1))
+    }
+
+    /// Returns `true` if the histogram has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
     }
 
     /// Gets the data currently stored.
@@ -110,12 +417,374 @@ impl<T: Eq + Hash + Display + Ord> Histogram<T> {
 
     /// Sets the data currently stored.
     pub fn set_data_list(&mut self, data: Vec<T>) {
-        let mut map = BTreeMap::<T, u128>::new();
-        for i in data {
-            let stat = map.entry(i).or_insert(0);
-            *stat += 1;
+        self.data.clear();
+        self.extend(data);
+    }
+
+    /// Gets the total count of all entries.
+    pub fn total(&self) -> u128 {
+        self.data.values().sum()
+    }
+
+    /// Adds the counts of another histogram to this one.
+    pub fn merge(&mut self, other: &Self)
+    where
+        T: Clone,
+    {
+        for (key, value) in other.data.iter() {
+            *self.data.entry(key.clone()).or_insert(0) += value;
+        }
+    }
+
+    /// Takes the counts of another histogram away from this one.
+    ///
+    /// Counts don't go below 0, and entries the subtraction brings to 0 are
+    /// removed. Entries that were already 0 are kept.
+    pub fn subtract(&mut self, other: &Self) {
+        for (key, value) in other.data.iter() {
+            let Some(count) = self.data.get_mut(key) else {
+                continue;
+            };
+            if *count > 0 && *count <= *value {
+                self.data.remove(key);
+            } else {
+                *count = count.saturating_sub(*value);
+            }
+        }
+    }
+
+    /// Gets the probability of each entry, its count over the total count.
+    pub fn normalise(&self) -> BTreeMap<&T, f64> {
+        let total = self.total().max(1) as f64;
+
+        self.data
+            .iter()
+            .map(|(key, value)| (key, *value as f64 / total))
+            .collect()
+    }
+
+    /// Gets the count of each entry and all entries before it.
+    pub fn cumulative(&self) -> BTreeMap<&T, u128> {
+        self.data
+            .iter()
+            .scan(0, |sum, (key, value)| {
+                *sum += value;
+                Some((key, *sum))
+            })
+            .collect()
+    }
+
+    /// Gets the smallest entry with at least the fraction `q` of the total count
+    /// at or before it.
+    ///
+    /// None would be returned if the histogram is empty or q is not from 0 to 1.
+    pub fn quantile(&self, q: f64) -> Option<&T> {
+        if !(0.0..=1.0).contains(&q) {
+            return None;
+        }
+        let target = (q * self.total() as f64).ceil() as u128;
+
+        self.cumulative()
+            .into_iter()
+            .find(|(_, sum)| *sum >= target.max(1))
+            .map(|(key, _)| key)
+    }
+
+    /// Gets the smallest entry with at least `p` percent of the total count at
+    /// or before it.
+    ///
+    /// None would be returned if the histogram is empty or p is not from 0 to 100.
+    pub fn percentile(&self, p: f64) -> Option<&T> {
+        self.quantile(p / 100.0)
+    }
+}
+
+impl<T: Eq + Hash + Display + Ord + Sample> Histogram<T> {
+    /// Gets the mean of the entries weighted by their counts.
+    ///
+    /// None would be returned if the histogram is empty.
+    pub fn mean(&self) -> Option<f64> {
+        let total = self.total();
+        let sum: f64 = self
+            .data
+            .iter()
+            .map(|(key, value)| key.to_f64() * *value as f64)
+            .sum();
+
+        (total > 0).then(|| sum / total as f64)
+    }
+
+    /// Renders a box and whisker plot across the width.
+    ///
+    /// The box goes from the first to the third quartile with a `|` at the
+    /// median, and the whiskers reach the furthest keys within 1.5 times the
+    /// interquartile range of the box. Keys beyond the whiskers are outliers,
+    /// drawn as `o`. An empty histogram is rendered as "No data."
+    pub fn render_box_plot<W: fmt::Write>(&self, f: &mut W, options: &Options) -> fmt::Result {
+        let (Some(q1), Some(median), Some(q3)) =
+            (self.quantile(0.25), self.quantile(0.5), self.quantile(0.75))
+        else {
+            return writeln!(f, "No data.");
+        };
+        let (q1, median, q3) = (q1.to_f64(), median.to_f64(), q3.to_f64());
+        let keys: Vec<f64> = self
+            .data
+            .iter()
+            .filter(|x| *x.1 > 0)
+            .map(|x| x.0.to_f64())
+            .collect();
+        let (min, max) = (keys[0], keys[keys.len() - 1]);
+
+        // Tukey's fences
+        let iqr = q3 - q1;
+        let (low_fence, high_fence) = (q1 - 1.5 * iqr, q3 + 1.5 * iqr);
+        let mut inside = keys
+            .iter()
+            .filter(|x| (low_fence..=high_fence).contains(*x));
+        // The quartiles are keys, so there is always a key inside
+        let low = inside.next().copied().unwrap_or(q1);
+        let high = inside.next_back().copied().unwrap_or(low);
+        let outliers: Vec<f64> = keys
+            .iter()
+            .filter(|x| !(low_fence..=high_fence).contains(*x))
+            .copied()
+            .collect();
+
+        let width = options.max_width.unwrap_or_else(terminal_width).max(10);
+        let position = |x: f64| match max > min {
+            true => ((x - min) / (max - min) * (width - 1) as f64).round() as usize,
+            false => 0,
+        };
+
+        let mut line = vec![' '; width];
+        line[position(low)..=position(high)].fill('-');
+        line[position(q1)..=position(q3)].fill('=');
+        for (x, symbol) in [(low, '|'), (high, '|'), (q1, '['), (q3, ']'), (median, '|')] {
+            line[position(x)] = symbol;
+        }
+        for x in &outliers {
+            line[position(*x)] = 'o';
+        }
+        writeln!(f, "{}", line.into_iter().collect::<String>().trim_end())?;
+
+        writeln!(
+            f,
+            "Min: {min}, Q1: {q1}, Median: {median}, Q3: {q3}, Max: {max}, Outliers: {}",
+            outliers.len()
+        )
+    }
+}
+
+impl<T: Eq + Hash + Display + Ord> FromIterator<T> for Histogram<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut output = Self::new(BTreeMap::new());
+        output.extend(iter);
+
+        output
+    }
+}
+
+impl<T: Eq + Hash + Display + Ord> Extend<T> for Histogram<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for i in iter {
+            *self.data.entry(i).or_insert(0) += 1;
+        }
+    }
+}
+
+impl<T: Eq + Hash + Display + Ord> Add for Histogram<T> {
+    type Output = Self;
+
+    fn add(mut self, other: Self) -> Self {
+        for (key, value) in other.data {
+            *self.data.entry(key).or_insert(0) += value;
+        }
+
+        self
+    }
+}
+
+impl<T: Eq + Hash + Display + Ord> Sub for Histogram<T> {
+    type Output = Self;
+
+    fn sub(mut self, other: Self) -> Self {
+        self.subtract(&other);
+        self
+    }
+}
+
+impl<T: Eq + Hash + Display + Ord> Display for Histogram<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.render(f, &self.options)
+    }
+}
+
+/// The symbols of the series of a multi-series histogram, in order.
+pub const SYMBOLS: [char; 8] = ['*', '#', '+', 'o', '=', '%', '@', 'x'];
+
+/// How the bars of a multi-series histogram are laid out.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Layout {
+    /// A bar for every series, one under another.
+    #[default]
+    Grouped,
+    /// The bars of every series joined into one bar.
+    Stacked,
+}
+
+/// Several histograms drawn together, sharing the keys and scale.
+///
+/// Each series is drawn with its own symbol from [`SYMBOLS`], and a legend of
+/// the symbols is written under the bars. The bars are always horizontal.
+pub struct MultiHistogram<T: Eq + Hash> {
+    /// The name and histogram of each series.
+    series: Vec<(String, Histogram<T>)>,
+}
+
+impl<T: Eq + Hash + Display + Ord> Default for MultiHistogram<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Eq + Hash + Display + Ord> MultiHistogram<T> {
+    /// Constructs a multi-series histogram without any series.
+    pub fn new() -> Self {
+        Self { series: Vec::new() }
+    }
+
+    /// Adds a series.
+    pub fn push(&mut self, name: &str, histogram: Histogram<T>) {
+        self.series.push((name.to_string(), histogram));
+    }
+
+    /// Gets the name and histogram of each series.
+    pub fn series(&self) -> &[(String, Histogram<T>)] {
+        &self.series
+    }
+
+    /// Gets the keys of every series.
+    pub fn keys(&self) -> BTreeSet<&T> {
+        self.series
+            .iter()
+            .flat_map(|(_, x)| x.data().keys())
+            .collect()
+    }
+
+    /// Gets the count of a key in a series.
+    fn count(histogram: &Histogram<T>, key: &T) -> u128 {
+        histogram.data().get(key).copied().unwrap_or(0)
+    }
+
+    /// Prints the histogram to stdout, scaled to fit the terminal if needed.
+    pub fn print(&self, layout: Layout) {
+        let options = Options {
+            scale: Scale::Auto,
+            ..Options::default()
+        };
+        let mut output = String::new();
+        self.render(&mut output, layout, &options)
+            .expect("Writing to a String never fails.");
+
+        print!("{output}");
+    }
+
+    /// Renders the histogram to a formatter or a String.
+    ///
+    /// The direction in the options is not used.
+    pub fn render<W: fmt::Write>(
+        &self,
+        f: &mut W,
+        layout: Layout,
+        options: &Options,
+    ) -> fmt::Result {
+        let keys = self.keys();
+        if keys.is_empty() {
+            return writeln!(f, "No data.");
+        }
+
+        let width = options.width;
+        let total =
+            |key: &T| -> u128 { self.series.iter().map(|(_, x)| Self::count(x, key)).sum() };
+        let highest = match layout {
+            Layout::Grouped => self
+                .series
+                .iter()
+                .filter_map(|(_, x)| x.get_highest())
+                .map(|x| *x.1)
+                .max()
+                .unwrap_or(0),
+            Layout::Stacked => keys.iter().map(|x| total(x)).max().unwrap_or(0),
+        };
+        let label_width = match options.label {
+            Label::Key => width + 1,
+            _ => 2 * width + 1,
+        };
+        let max_width = options.max_width.unwrap_or_else(terminal_width);
+        let unit = options.unit(highest, max_width.saturating_sub(label_width));
+        // The bar of a count in a symbol, rounding to the nearest character
+        let bar = |value: u128, symbol: char| -> String {
+            let length = match unit {
+                Some(unit) => (value + unit / 2) / unit,
+                None => value,
+            };
+            std::iter::repeat_n(symbol, length as usize).collect()
+        };
+
+        for key in keys {
+            match layout {
+                Layout::Grouped => {
+                    for (i, (_, histogram)) in self.series.iter().enumerate() {
+                        let value = Self::count(histogram, key);
+                        // The key is only written next to the first bar
+                        let (key, separator) = match i {
+                            0 => (key.to_string(), '/'),
+                            _ => (String::new(), ' '),
+                        };
+                        match options.label {
+                            Label::Key => write!(f, "{key:>width$} ")?,
+                            _ => write!(
+                                f,
+                                "{key:>width$}{separator}{:<width$}",
+                                histogram.label(value, options)
+                            )?,
+                        }
+                        writeln!(f, "{}", bar(value, SYMBOLS[i % SYMBOLS.len()]))?;
+                    }
+                }
+                Layout::Stacked => {
+                    let value = total(key);
+                    match options.label {
+                        Label::Key => write!(f, "{key:>width$} ")?,
+                        Label::Count => write!(f, "{key:>width$}/{value:<width$}")?,
+                        Label::Percent => {
+                            let all: u128 = self.series.iter().map(|(_, x)| x.total()).sum();
+                            let percent =
+                                format!("{:.1}%", 100.0 * value as f64 / all.max(1) as f64);
+                            write!(f, "{key:>width$}/{percent:<width$}")?
+                        }
+                    }
+                    for (i, (_, histogram)) in self.series.iter().enumerate() {
+                        let value = Self::count(histogram, key);
+                        write!(f, "{}", bar(value, SYMBOLS[i % SYMBOLS.len()]))?;
+                    }
+                    writeln!(f)?;
+                }
+            }
+        }
+
+        // Legend
+        let legend: Vec<String> = self
+            .series
+            .iter()
+            .enumerate()
+            .map(|(i, (name, _))| format!("{} = {name}", SYMBOLS[i % SYMBOLS.len()]))
+            .collect();
+        writeln!(f, "Legend: {}", legend.join(", "))?;
+        if let Some(unit) = unit {
+            writeln!(f, "Scale: each symbol = {unit} counts")?;
         }
 
-        self.data = map;
+        Ok(())
     }
 }
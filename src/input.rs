@@ -0,0 +1,37 @@
+//! Reading guesses and other answers from standard input.
+use std::{
+    io::{self, Write},
+    str::FromStr,
+};
+
+/// Prints the prompt and reads a line from standard input.
+pub fn read_line(prompt: &str) -> String {
+    // Printing prompt
+    print!("{prompt}");
+    io::stdout().flush().expect("Failed to flush stdout.");
+
+    let mut line = String::new();
+    io::stdin()
+        .read_line(&mut line)
+        .expect("Unable to read line.");
+
+    line
+}
+
+/// Reads lines from standard input until one can be parsed.
+///
+/// `invalid` is printed for every line that can't be parsed, before the prompt
+/// is printed again.
+pub fn read_parsed<T: FromStr>(prompt: &str, invalid: &str) -> T {
+    loop {
+        match read_line(prompt).trim().parse() {
+            Ok(value) => return value,
+            Err(_) => println!("{invalid}"),
+        }
+    }
+}
+
+/// Reads a number from standard input, asking again until a number is typed.
+pub fn read_number(prompt: &str) -> i128 {
+    read_parsed(prompt, "Invalid Number")
+}
@@ -0,0 +1,275 @@
+//! What is known about the number from the answers so far.
+//!
+//! The numbers the number can be are kept as a set of intervals, so answers that
+//! exclude a single number, like a wrong guess with equality only feedback, leave
+//! a hole instead of being lost. An answer that leaves no numbers contradicts the
+//! answers before it.
+use std::cmp::Ordering;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remove() {
+        let mut set = CandidateSet::new(1, 10);
+        set.remove(5);
+        assert_eq!(set.intervals(), &[(1, 4), (6, 10)]);
+        set.remove(1);
+        set.remove(10);
+        assert_eq!(set.intervals(), &[(2, 4), (6, 9)]);
+        // Removing a number not in the set does nothing
+        set.remove(5);
+        assert_eq!(set.intervals(), &[(2, 4), (6, 9)]);
+        set.remove(3);
+        set.remove(2);
+        set.remove(4);
+        assert_eq!(set.intervals(), &[(6, 9)]);
+    }
+}
+
+/// A set of numbers stored as sorted, separate intervals inclusive of both ends.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CandidateSet {
+    /// The intervals, from the smallest to the largest, with gaps between them.
+    intervals: Vec<(i128, i128)>,
+}
+
+impl CandidateSet {
+    /// Creates a set of the numbers `min..=max`, which is empty if min > max.
+    pub fn new(min: i128, max: i128) -> Self {
+        let intervals = match min <= max {
+            true => vec![(min, max)],
+            false => Vec::new(),
+        };
+
+        Self { intervals }
+    }
+
+    /// Gets the intervals, from the smallest to the largest.
+    pub fn intervals(&self) -> &[(i128, i128)] {
+        &self.intervals
+    }
+
+    /// Gets the amount of numbers in the set, saturating at `u128::MAX`.
+    pub fn size(&self) -> u128 {
+        self.intervals.iter().fold(0u128, |size, (a, b)| {
+            size.saturating_add(b.abs_diff(*a)).saturating_add(1)
+        })
+    }
+
+    /// Returns `true` if there are no numbers in the set.
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Gets the smallest number in the set.
+    pub fn min(&self) -> Option<i128> {
+        self.intervals.first().map(|x| x.0)
+    }
+
+    /// Gets the largest number in the set.
+    pub fn max(&self) -> Option<i128> {
+        self.intervals.last().map(|x| x.1)
+    }
+
+    /// Returns `true` if the number is in the set.
+    pub fn contains(&self, number: i128) -> bool {
+        self.intervals
+            .binary_search_by(|(a, b)| match (number < *a, number > *b) {
+                (true, _) => Ordering::Greater,
+                (_, true) => Ordering::Less,
+                _ => Ordering::Equal,
+            })
+            .is_ok()
+    }
+
+    /// Gets the number at the index, counting from the smallest number.
+    pub fn nth(&self, mut index: u128) -> Option<i128> {
+        for (a, b) in &self.intervals {
+            // The last index of the interval, as the full range has u128::MAX + 1 numbers
+            let last = b.abs_diff(*a);
+            if index <= last {
+                return a.checked_add_unsigned(index);
+            }
+            index -= last + 1;
+        }
+
+        None
+    }
+
+    /// Gets the middle number of the set, rounded down.
+    pub fn median(&self) -> Option<i128> {
+        self.nth(self.size().checked_sub(1)? / 2)
+    }
+
+    /// Gets the Shannon entropy of the number in bits, when every number in the set is equally likely.
+    pub fn entropy(&self) -> f64 {
+        match self.size() {
+            0 => 0.0,
+            size => (size as f64).log2(),
+        }
+    }
+
+    /// Keeps only the numbers in `min..=max`.
+    pub fn restrict(&mut self, min: i128, max: i128) {
+        self.intervals = self
+            .intervals
+            .iter()
+            .map(|(a, b)| (*a.max(&min), *b.min(&max)))
+            .filter(|(a, b)| a <= b)
+            .collect();
+    }
+
+    /// Removes every number from the set.
+    pub fn clear(&mut self) {
+        self.intervals.clear();
+    }
+
+    /// Removes a number from the set.
+    pub fn remove(&mut self, number: i128) {
+        let Some(i) = self
+            .intervals
+            .iter()
+            .position(|(a, b)| (*a..=*b).contains(&number))
+        else {
+            return;
+        };
+        let (a, b) = self.intervals[i];
+
+        // The parts of the interval on either side of the number
+        let below = (number > a).then(|| (a, number - 1));
+        let above = (number < b).then(|| (number + 1, b));
+        self.intervals.splice(i..=i, below.into_iter().chain(above));
+    }
+
+    /// Keeps only the numbers where the function returns `true`.
+    ///
+    /// Every number in the set is checked, so this should only be used on small sets.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(i128) -> bool,
+    {
+        let mut intervals: Vec<(i128, i128)> = Vec::new();
+
+        for number in self.iter().filter(|x| f(*x)) {
+            match intervals.last_mut() {
+                Some((_, b)) if *b + 1 == number => *b = number,
+                _ => intervals.push((number, number)),
+            }
+        }
+
+        self.intervals = intervals;
+    }
+
+    /// Iterates over the numbers in the set, from the smallest to the largest.
+    pub fn iter(&self) -> impl Iterator<Item = i128> + '_ {
+        self.intervals.iter().flat_map(|(a, b)| *a..=*b)
+    }
+}
+
+/// What is known about the number, from the answers so far.
+///
+/// An answer that contradicts the answers before it is rejected, leaving the
+/// knowledge as it was.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KnowledgeState {
+    /// The numbers the number can be.
+    candidates: CandidateSet,
+}
+
+impl KnowledgeState {
+    /// Creates the knowledge that the number is in `min..=max`.
+    pub fn new(min: i128, max: i128) -> Self {
+        Self {
+            candidates: CandidateSet::new(min, max),
+        }
+    }
+
+    /// Gets the numbers the number can be.
+    pub fn candidates(&self) -> &CandidateSet {
+        &self.candidates
+    }
+
+    /// Gets the amount of numbers the number can be.
+    pub fn size(&self) -> u128 {
+        self.candidates.size()
+    }
+
+    /// Gets the Shannon entropy of the number in bits, when every number left is equally likely.
+    pub fn entropy(&self) -> f64 {
+        self.candidates.entropy()
+    }
+
+    /// Gets the smallest and largest numbers the number can be.
+    ///
+    /// This function returns a tuple in the form (minimum, maximum) inclusive of the number.
+    pub fn bounds(&self) -> Option<(i128, i128)> {
+        Some((self.candidates.min()?, self.candidates.max()?))
+    }
+
+    /// Gets the number if only one number is left.
+    pub fn known(&self) -> Option<i128> {
+        match self.size() {
+            1 => self.candidates.min(),
+            _ => None,
+        }
+    }
+
+    /// Updates the knowledge with the answer to a guess, which is how the guess compares to the number.
+    ///
+    /// An Err would be returned if the answer contradicts the answers before.
+    #[allow(clippy::result_unit_err)]
+    pub fn observe(&mut self, guess: i128, result: Ordering) -> Result<(), ()> {
+        self.apply(|x| match result {
+            Ordering::Greater => match guess.checked_sub(1) {
+                Some(max) => x.restrict(i128::MIN, max),
+                None => x.clear(),
+            },
+            Ordering::Less => match guess.checked_add(1) {
+                Some(min) => x.restrict(min, i128::MAX),
+                None => x.clear(),
+            },
+            Ordering::Equal => x.restrict(guess, guess),
+        })
+    }
+
+    /// Updates the knowledge with whether a guess is correct.
+    ///
+    /// An Err would be returned if the answer contradicts the answers before.
+    #[allow(clippy::result_unit_err)]
+    pub fn observe_equality(&mut self, guess: i128, correct: bool) -> Result<(), ()> {
+        self.apply(|x| match correct {
+            true => x.restrict(guess, guess),
+            false => x.remove(guess),
+        })
+    }
+
+    /// Updates the knowledge with the answer to a yes or no question.
+    ///
+    /// Every number left is checked, so this should only be used on small ranges.
+    /// An Err would be returned if the answer contradicts the answers before.
+    #[allow(clippy::result_unit_err)]
+    pub fn observe_predicate<F>(&mut self, mut question: F, answer: bool) -> Result<(), ()>
+    where
+        F: FnMut(i128) -> bool,
+    {
+        self.apply(|x| x.retain(|number| question(number) == answer))
+    }
+
+    /// Applies an update, unless it leaves no numbers.
+    fn apply<F>(&mut self, update: F) -> Result<(), ()>
+    where
+        F: FnOnce(&mut CandidateSet),
+    {
+        let mut candidates = self.candidates.clone();
+        update(&mut candidates);
+
+        if candidates.is_empty() {
+            return Err(());
+        }
+        self.candidates = candidates;
+
+        Ok(())
+    }
+}
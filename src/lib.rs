@@ -1,7 +1,26 @@
 //! A number guessing game.
+pub mod analysis;
 pub mod auto_guesser;
+pub mod binned;
+pub mod bulls_cows;
+pub mod circular;
+pub mod cooperative;
+pub mod delayed;
+pub mod drifting;
+pub mod efficiency;
+pub mod egg_drop;
+pub mod equality;
+pub mod group;
 pub mod histogram;
+pub mod input;
+pub mod knowledge;
 pub mod number_game;
+pub mod parallel;
+pub mod plot;
+pub mod price;
+pub mod questions;
+pub mod race;
+pub mod unbounded;
 
 pub use auto_guesser::AutoNumberGuesser;
 pub use number_game::NumberGuessingGame;
@@ -34,6 +53,14 @@ impl Statistics {
         println!("Standard Deviation: {}", self.std);
     }
 
+    /// Prints the mean compared with the mean expected from analysis into standard output.
+    pub fn print_expected(&self, name: &str, expected: f32) {
+        println!(
+            "Expected Mean ({name}): {expected} ({:+} from the mean)",
+            self.mean - expected
+        );
+    }
+
     /// Gets the mean value of the data.
     pub fn mean(&self) -> f32 {
         self.mean
@@ -50,30 +77,40 @@ impl Statistics {
     }
 }
 
-impl From<Vec<u128>> for Statistics {
+impl TryFrom<Vec<u128>> for Statistics {
+    type Error = ();
+
     /// Creates a new Statistics using a `std::vec::Vec` containting all the data.
     ///
+    /// An Err would be returned if there is no data.
+    ///
     /// ```
     /// use guess_my_number_rs::Statistics;
     ///
     /// let data: Vec<u128> = vec![1, 2, 3, 4];
     ///
-    /// let stats = Statistics::from(data);
+    /// let stats = Statistics::try_from(data).unwrap();
     /// assert_eq!(stats.mean(), 2.5);
     /// assert_eq!(stats.std(), 1.118034);
     /// assert_eq!(stats.variance(), 1.25);
+    ///
+    /// assert!(Statistics::try_from(Vec::new()).is_err());
     /// ```
-    fn from(source: Vec<u128>) -> Self {
+    fn try_from(source: Vec<u128>) -> Result<Self, ()> {
+        if source.is_empty() {
+            return Err(());
+        }
+
         // Calculating values
         let mean = source.iter().sum::<u128>() as f32 / source.len() as f32;
         let mean_square = source.iter().map(|x| x * x).sum::<u128>() as f32 / source.len() as f32;
         let variance = mean_square - mean * mean;
         let std = variance.sqrt();
 
-        Self {
+        Ok(Self {
             mean,
             std,
             variance,
-        }
+        })
     }
 }
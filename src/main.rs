@@ -1,8 +1,26 @@
-use std::io::{self, Write};
+use std::{
+    fmt::Write as _,
+    io::{self, Write},
+};
 
 use clap::{Parser, *};
 use guess_my_number_rs::{
-    histogram::{Direction, Histogram},
+    analysis::{self, GameReport, SecretReport},
+    auto_guesser::{Bisection, CircularBisection, KnuthMinimax, Strategy},
+    bulls_cows::BullsCowsGame,
+    circular::CircularGame,
+    cooperative::CoopGame,
+    drifting::{DriftTracking, DriftingGame},
+    egg_drop::{self, Costs, EggDropGame, Objective, OptimalEggDrop},
+    equality::{self, EqualityGame, EqualityStrategy, PriorWeighted, RandomOrder, Sequential},
+    group::{AdaptiveSplitting, GroupGame, QueryKind},
+    histogram::{Direction, Histogram, Layout, MultiHistogram, Options},
+    number_game::{DEFAULT_HINT_BUDGET, HINT_PENALTY},
+    plot,
+    price::{self, Bidder, PriceGame, Risk},
+    questions::{self, InformationGain, Predicate, QuestionGame},
+    race::{self, RaceGame, RaceOptimal},
+    unbounded::{BentleyYao, Galloping, UnboundedGame},
     AutoNumberGuesser, NumberGuessingGame, Statistics,
 };
 
@@ -14,6 +32,94 @@ enum Player {
     Human,
 }
 
+/// Feedback given after each guess.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Feedback {
+    /// The guess is too high, too low or correct.
+    Ordering,
+    /// The guess is only correct or incorrect.
+    Equality,
+}
+
+/// Order the AI guesses in with equality only feedback.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Order {
+    /// From the minimum to the maximum.
+    Sequential,
+    /// A random order without guessing a number twice.
+    Random,
+    /// From the most to the least likely, with numbers near the middle more likely.
+    Prior,
+}
+
+/// What the AI minimises in the egg drop game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Minimise {
+    /// The highest cost of any number.
+    Worst,
+    /// The mean cost.
+    Expected,
+}
+
+/// The other player in a race.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Opponent {
+    /// Another human player.
+    Human,
+    /// An AI guessing the middle.
+    Bisection,
+    /// An AI playing the minimax strategy of the race.
+    Optimal,
+}
+
+/// A strategy the AI can guess with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Algorithm {
+    /// Guesses the middle of the range.
+    Bisection,
+    /// Guesses by the minimax strategy of a race.
+    RaceOptimal,
+    /// Gallops up from the minimum, then bisects.
+    Galloping,
+    /// Gallops up from the minimum doubling the exponent, then bisects.
+    BentleyYao,
+}
+
+/// How the bars of several strategies are drawn together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Bars {
+    /// A bar for every strategy, one under another.
+    Grouped,
+    /// The bars of every strategy joined into one bar.
+    Stacked,
+}
+
+/// How the guesses of the AI are summarised.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Chart {
+    /// A bar for every amount of guesses.
+    Bars,
+    /// The fraction of games won within every amount of guesses.
+    Ecdf,
+    /// A box and whisker plot of the guesses.
+    Box,
+    /// A single line of bars.
+    Sparkline,
+}
+
+/// How an AI bidder bids in "The Price is Right".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Bid {
+    /// Bids a quarter of the way into the range.
+    Cautious,
+    /// Bids the middle of the range.
+    Balanced,
+    /// Bids three quarters of the way into the range.
+    Bold,
+    /// Bids just above another bid, taking the widest gap.
+    Strategic,
+}
+
 /// Argument parser for NumberGuessingGame.
 #[derive(Parser)]
 struct Arguments {
@@ -28,6 +134,12 @@ struct Arguments {
 
 #[derive(Debug, Args)]
 /// Arguments to set the game options manually.
+///
+/// Only one game mode can be chosen.
+#[command(group(ArgGroup::new("mode").args([
+    "feedback", "egg_drop", "circular", "drift", "group", "questions", "bulls_cows", "race", "price", "coop",
+    "unbounded",
+])))]
 struct Manual {
     /// Sets the range of what the number can be.
     ///
@@ -50,6 +162,161 @@ struct Manual {
     /// Number of iterations the AI runs
     #[arg(long, default_value_t = 200)]
     iteration: u32,
+
+    /// Analyse how many guesses the AI needs for every number in the range.
+    #[arg(long, requires = "ai", conflicts_with = "mode")]
+    analyse: bool,
+
+    /// Output csv file of the analysis.
+    #[arg(long, requires = "analyse")]
+    csv: Option<String>,
+
+    /// Feedback given after each guess.
+    #[arg(long, value_enum, default_value_t = Feedback::Ordering)]
+    feedback: Feedback,
+
+    /// Order the AI guesses in with equality only feedback.
+    #[arg(long, value_enum, default_value_t = Order::Sequential)]
+    order: Order,
+
+    /// Egg drop options.
+    #[command(flatten)]
+    egg_drop: EggDrop,
+
+    /// Play on a ring where the maximum is followed by the minimum.
+    #[arg(long)]
+    circular: bool,
+
+    /// Play without a known maximum, only the minimum of the range is told.
+    #[arg(long)]
+    unbounded: bool,
+
+    /// Largest step the number moves after every guess.
+    #[arg(long)]
+    drift: Option<i128>,
+
+    /// Most guesses the AI makes before giving up on a moving number.
+    #[arg(long, requires = "drift", default_value_t = 1000)]
+    limit: u128,
+
+    /// Number of hidden numbers to find with group testing queries.
+    #[arg(long, value_parser = clap::value_parser!(u64).range(1..))]
+    group: Option<u64>,
+
+    /// Ask yes or no questions about the number, like twenty questions.
+    #[arg(long)]
+    questions: bool,
+
+    /// A question the AI can ask, can be given more than once.
+    ///
+    /// A pool of parity, prime, square, remainder, digit sum and `x < v`
+    /// questions is used if not given.
+    #[arg(long, requires = "questions", value_parser = validate_question)]
+    pool: Vec<String>,
+
+    /// Guess a code with the given number of digits, answered with bulls and cows.
+    #[arg(long, value_parser = clap::value_parser!(u64).range(1..=10))]
+    bulls_cows: Option<u64>,
+
+    /// Every digit in the code is different.
+    #[arg(long, requires = "bulls_cows")]
+    distinct: bool,
+
+    /// Race another player to guess the same number, seeing every answer.
+    ///
+    /// With an AI player, the AI strategies race each other.
+    #[arg(long)]
+    race: bool,
+
+    /// The other player of a human in a race.
+    #[arg(long, requires = "race", value_enum, default_value_t = Opponent::Optimal)]
+    opponent: Opponent,
+
+    /// "The Price is Right" options.
+    #[command(flatten)]
+    price: Price,
+
+    /// Number of hints a human player can ask for.
+    #[arg(long, requires = "human", default_value_t = DEFAULT_HINT_BUDGET)]
+    hints: u32,
+
+    /// Take turns with the AI guessing the same number, with the AI explaining its guesses.
+    #[arg(long, requires = "human")]
+    coop: bool,
+
+    /// Output svg file of a chart of the guesses the AI took.
+    #[arg(long, requires = "ai", conflicts_with_all = ["mode", "analyse"])]
+    plot: Option<String>,
+
+    /// How the guesses the AI took are summarised.
+    #[arg(
+        long,
+        requires = "ai",
+        value_enum,
+        default_value_t = Chart::Bars,
+        conflicts_with_all = ["mode", "analyse"]
+    )]
+    chart: Chart,
+
+    /// Compare the guesses of the given strategies in one histogram.
+    #[arg(
+        long,
+        requires = "ai",
+        value_enum,
+        value_delimiter = ',',
+        conflicts_with_all = ["mode", "analyse", "plot"]
+    )]
+    compare: Vec<Algorithm>,
+
+    /// How the bars of the compared strategies are drawn.
+    #[arg(long, requires = "compare", value_enum, default_value_t = Bars::Grouped)]
+    bars: Bars,
+}
+
+/// Arguments for "The Price is Right".
+#[derive(Debug, Clone, Args)]
+struct Price {
+    /// Play "The Price is Right", where the closest bid without going over wins.
+    #[arg(long)]
+    price: bool,
+
+    /// The AI bidders, in the order they bid.
+    #[arg(
+        long,
+        requires = "price",
+        value_enum,
+        value_delimiter = ',',
+        default_value = "cautious,balanced,bold,strategic"
+    )]
+    bidders: Vec<Bid>,
+
+    /// Number of rounds, narrowing the range after each round.
+    #[arg(long, requires = "price", default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    rounds: u32,
+}
+
+/// Arguments for the egg drop game.
+#[derive(Debug, Clone, Args)]
+struct EggDrop {
+    /// Play the egg drop game, where too high guesses break an egg.
+    #[arg(long)]
+    egg_drop: bool,
+
+    /// Number of eggs, unlimited if not given.
+    #[arg(long, requires = "egg_drop", value_parser = clap::value_parser!(u32).range(1..))]
+    eggs: Option<u32>,
+
+    /// Cost of a too low or correct guess.
+    #[arg(long, requires = "egg_drop", default_value_t = 1)]
+    low_cost: u128,
+
+    /// Cost of a too high guess.
+    #[arg(long, requires = "egg_drop", default_value_t = 1)]
+    high_cost: u128,
+
+    /// What the AI minimises.
+    #[arg(long, requires = "egg_drop", value_enum, default_value_t = Minimise::Worst)]
+    minimise: Minimise,
 }
 
 /// Arguments to set the range seperately.
@@ -67,6 +334,32 @@ struct Range {
 
 fn main() {
     let args = Arguments::parse();
+    let (analyse, csv) = (args.manual.analyse, args.manual.csv.clone());
+    let (feedback, order) = (args.manual.feedback, args.manual.order);
+    let egg_drop = args
+        .manual
+        .egg_drop
+        .egg_drop
+        .then(|| args.manual.egg_drop.clone());
+    let circular = args.manual.circular;
+    let unbounded = args.manual.unbounded;
+    let (drift, limit) = (args.manual.drift, args.manual.limit);
+    let group = args.manual.group.map(|x| x as usize);
+    let questions = args.manual.questions;
+    let bulls_cows = args.manual.bulls_cows.map(|x| x as usize);
+    let distinct = args.manual.distinct;
+    let (race, opponent) = (args.manual.race, args.manual.opponent);
+    let price = args.manual.price.price.then(|| args.manual.price.clone());
+    let coop = args.manual.coop;
+    let hints = args.manual.hints;
+    let (plot, chart) = (args.manual.plot.clone(), args.manual.chart);
+    let (compare, bars) = (args.manual.compare.clone(), args.manual.bars);
+    let pool: Vec<Predicate> = args
+        .manual
+        .pool
+        .iter()
+        .map(|x| x.parse().unwrap())
+        .collect();
 
     // Getting Options
     let (player, min, max, iteration): (Player, i128, i128, u32) = if args.interactive {
@@ -125,12 +418,223 @@ fn main() {
         std::process::exit(1);
     }
 
+    if group.is_some_and(|x| max.abs_diff(min) < x as u128 - 1) {
+        eprintln!("There must be at least as many numbers in the range as hidden numbers");
+        std::process::exit(1);
+    }
+
+    if feedback == Feedback::Equality
+        && order != Order::Sequential
+        && max.abs_diff(min) >= equality::MAX_STORED
+    {
+        eprintln!(
+            "There can be at most {} numbers in the range to guess in this order",
+            equality::MAX_STORED
+        );
+        std::process::exit(1);
+    }
+
+    if matches!(player, Player::AI) && bulls_cows.is_some_and(|x| x > KnuthMinimax::MAX_DIGITS) {
+        eprintln!(
+            "The AI can only play bulls and cows with up to {} digits",
+            KnuthMinimax::MAX_DIGITS
+        );
+        std::process::exit(1);
+    }
+
+    let race_optimal = match player {
+        Player::AI => race || compare.contains(&Algorithm::RaceOptimal),
+        Player::Human => race && opponent == Opponent::Optimal,
+    };
+    if race_optimal && max.abs_diff(min) >= race::MAX_SIZE {
+        eprintln!(
+            "There can be at most {} numbers in the range for the race strategy",
+            race::MAX_SIZE
+        );
+        std::process::exit(1);
+    }
+
+    if questions && max.abs_diff(min) >= questions::MAX_CANDIDATES {
+        eprintln!(
+            "There can be at most {} numbers in the range to ask questions",
+            questions::MAX_CANDIDATES
+        );
+        std::process::exit(1);
+    }
+
+    if circular && max.abs_diff(min) == u128::MAX {
+        eprintln!("The ring can't be the whole range of numbers");
+        std::process::exit(1);
+    }
+
+    if egg_drop.is_some() && max.abs_diff(min) >= egg_drop::MAX_SIZE {
+        eprintln!(
+            "There can be at most {} numbers in the range to drop eggs",
+            egg_drop::MAX_SIZE
+        );
+        std::process::exit(1);
+    }
+
+    if analyse && max.abs_diff(min) >= analysis::MAX_SECRETS {
+        eprintln!(
+            "There can be at most {} numbers in the range to analyse",
+            analysis::MAX_SECRETS
+        );
+        std::process::exit(1);
+    }
+
+    if drift.is_some_and(|x| x < 0) {
+        eprintln!("Drift must be at least 0");
+        std::process::exit(1);
+    }
+
     // Main logic
     match player {
+        Player::Human if coop => {
+            let mut game = CoopGame::new(min, max).unwrap();
+
+            loop {
+                println!("{}", game.get_guess());
+                if game.is_over() {
+                    break;
+                }
+
+                println!("{}", game.explain());
+                println!("{}", game.ai_guess());
+                if game.is_over() {
+                    break;
+                }
+            }
+
+            print!("{}", game.summary());
+        }
+        Player::Human if price.is_some() => play_price_human(min, max, &price.unwrap()),
+        Player::Human if race => play_race_human(min, max, opponent),
+        Player::Human if bulls_cows.is_some() => {
+            let mut game = BullsCowsGame::new(bulls_cows.unwrap(), distinct).unwrap();
+            while !game.get_guess() {}
+        }
+        Player::Human if questions => {
+            let mut game = QuestionGame::new(min, max).unwrap();
+            while !game.get_question() {}
+        }
+        Player::Human if group.is_some() => {
+            let mut game = GroupGame::new(min, max, group.unwrap()).unwrap();
+            println!("There are {} hidden numbers.", game.k());
+            while !game.get_query() {}
+        }
+        Player::Human if drift.is_some() => {
+            let mut game = DriftingGame::new(min, max, drift.unwrap()).unwrap();
+            while !game.get_guess() {}
+        }
+        Player::Human if unbounded => {
+            let mut game = UnboundedGame::new(Some(min));
+            game.reset_number(max.abs_diff(min));
+            println!("The number is at least {min}.");
+            while !game.get_guess() {}
+        }
+        Player::Human if circular => {
+            let mut game = CircularGame::new(min, max).unwrap();
+            print!("{}", game.render(6, None));
+            while !game.get_guess() {}
+        }
+        Player::Human if egg_drop.is_some() => {
+            let args = egg_drop.unwrap();
+            let costs = Costs::new(args.low_cost, args.high_cost);
+            let mut game = EggDropGame::new(min, max, costs, args.eggs).unwrap();
+            while !game.get_guess() {}
+        }
+        Player::Human if feedback == Feedback::Equality => {
+            let mut game = EqualityGame::new(min, max).unwrap();
+            while !game.get_guess() {}
+        }
         Player::Human => {
             let mut game = NumberGuessingGame::new(min, max).unwrap();
+            game.set_hint_budget(hints);
+            game.set_record_history(true);
+            println!("Type \"hint\" for a hint, each one costs {HINT_PENALTY} points.");
             while !game.get_guess() {}
+
+            GameReport::new(&game).print();
+        }
+        Player::AI if analyse => {
+            let report = SecretReport::new(min, max, Box::<Bisection>::default()).unwrap();
+            report.print(80);
+
+            if let Some(path) = csv {
+                let file = std::fs::File::create(&path).unwrap_or_else(|err| {
+                    eprintln!("{}", err);
+                    std::process::exit(1);
+                });
+
+                if let Err(err) = report.write_csv(file) {
+                    eprintln!("{}", err);
+                    std::process::exit(1);
+                }
+            }
+        }
+        Player::AI if price.is_some() => play_price(min, max, iteration, &price.unwrap()),
+        Player::AI if race => play_race(min, max, iteration),
+        Player::AI if !compare.is_empty() => play_compare(min, max, iteration, &compare, bars),
+        Player::AI if bulls_cows.is_some() => {
+            let mut game = BullsCowsGame::new(bulls_cows.unwrap(), distinct).unwrap();
+            let mut strategy = KnuthMinimax::new(game.digits(), distinct).unwrap();
+            let mut data: Vec<u128> = Vec::new();
+
+            for _ in 0..iteration {
+                game.reset_number();
+                data.push(game.solve(&mut strategy));
+            }
+
+            print_data(data);
+        }
+        Player::AI if questions => play_questions(min, max, iteration, pool),
+        Player::AI if group.is_some() => {
+            let mut game = GroupGame::new(min, max, group.unwrap()).unwrap();
+
+            for (kind, name) in [(QueryKind::Below, "below"), (QueryKind::Any, "any")] {
+                let mut data: Vec<u128> = Vec::new();
+
+                for _ in 0..iteration {
+                    game.reset_numbers();
+                    AdaptiveSplitting::new(kind).solve(&mut game);
+                    data.push(game.get_n_queries());
+                }
+
+                println!("Queries using \"{name}\":");
+                print_data(data);
+            }
+        }
+        Player::AI if drift.is_some() => play_drifting(min, max, iteration, drift.unwrap(), limit),
+        Player::AI if unbounded => {
+            let mut game = UnboundedGame::new(Some(min));
+            let mut galloping: Vec<u128> = Vec::new();
+            let mut bentley_yao: Vec<u128> = Vec::new();
+
+            for _ in 0..iteration {
+                game.reset_number(max.abs_diff(min));
+                galloping.push(game.solve(&mut Galloping::default()));
+                bentley_yao.push(game.solve(&mut BentleyYao::default()));
+            }
+
+            println!("Guesses using galloping search:");
+            print_data(galloping);
+            println!("Guesses using Bentley–Yao search:");
+            print_data(bentley_yao);
         }
+        Player::AI if circular => {
+            let mut game = CircularGame::new(min, max).unwrap();
+            let mut data: Vec<u128> = Vec::new();
+
+            for _ in 0..iteration {
+                game.reset_number();
+                data.push(game.solve(&mut CircularBisection::default()));
+            }
+
+            print_data(data);
+        }
+        Player::AI if egg_drop.is_some() => play_egg_drop(min, max, iteration, &egg_drop.unwrap()),
+        Player::AI if feedback == Feedback::Equality => play_equality(min, max, iteration, order),
         Player::AI => {
             let mut game = AutoNumberGuesser::new(min, max).unwrap();
             let mut data: Vec<u128> = Vec::new();
@@ -141,12 +645,333 @@ fn main() {
             }
 
             let histogram = Histogram::from_vec(data.clone());
-            histogram.print(Direction::Horizontal);
+            let mut output = String::new();
+            match chart {
+                Chart::Bars => histogram.render(&mut output, &Options::default()),
+                Chart::Ecdf => histogram.render_ecdf(&mut output, &Options::default()),
+                Chart::Box => histogram.render_box_plot(&mut output, &Options::default()),
+                Chart::Sparkline => writeln!(output, "{}", histogram.sparkline()),
+            }
+            .unwrap();
+            print!("{output}");
+
+            if let Some(path) = plot {
+                let svg = plot::histogram(&histogram, &format!("Guesses from {min} to {max}"));
+                if let Err(err) = std::fs::write(&path, svg) {
+                    eprintln!("{}", err);
+                    std::process::exit(1);
+                }
+            }
+
+            if let Ok(stats) = Statistics::try_from(data) {
+                stats.print();
+            }
+        }
+    }
+}
+
+/// Plays the equality only game with the AI and compares it with the expected results.
+fn play_equality(min: i128, max: i128, iteration: u32, order: Order) {
+    let mut game = EqualityGame::new(min, max).unwrap();
+    // The full i128 range has one more number than u128::MAX, which is too
+    // little to change the expected results
+    let size = max.abs_diff(min).saturating_add(1);
+
+    // Numbers near the middle are more likely for the prior
+    let weights: Vec<f64> = match order {
+        Order::Prior => (0..size)
+            .map(|x| (x.min(size - 1 - x) + 1) as f64)
+            .collect(),
+        _ => Vec::new(),
+    };
+    let mut strategy: Box<dyn EqualityStrategy> = match order {
+        Order::Sequential => Box::<Sequential>::default(),
+        Order::Random => Box::<RandomOrder>::default(),
+        Order::Prior => Box::new(PriorWeighted::new(weights.clone())),
+    };
+    let mut data: Vec<u128> = Vec::new();
+
+    for _ in 0..iteration {
+        match order {
+            Order::Prior => game.reset_number_with_prior(&weights).unwrap(),
+            _ => game.reset_number(),
+        }
+        data.push(game.solve(strategy.as_mut()));
+    }
+
+    let Some(stats) = print_data(data) else {
+        return;
+    };
+    match order {
+        Order::Prior => stats.print_expected("prior", equality::expected_prior(&weights)),
+        _ => stats.print_expected("in order", equality::expected_sequential(size)),
+    }
+    stats.print_expected("bisection", equality::expected_bisection(size));
+}
+
+/// Plays the egg drop game with the optimal AI.
+fn play_egg_drop(min: i128, max: i128, iteration: u32, args: &EggDrop) {
+    let costs = Costs::new(args.low_cost, args.high_cost);
+    let objective = match args.minimise {
+        Minimise::Worst => Objective::WorstCase,
+        Minimise::Expected => Objective::Expected,
+    };
+    let mut game = EggDropGame::new(min, max, costs, args.eggs).unwrap();
+    let mut strategy = OptimalEggDrop::new(costs, args.eggs, objective);
+    // Building the strategy, so the cost is known even without games
+    strategy.reset(min, max);
+    let mut data: Vec<u128> = Vec::new();
+
+    for _ in 0..iteration {
+        game.reset_number();
+        // The optimal strategy never runs out of eggs
+        data.push(game.solve(&mut strategy).unwrap());
+    }
+
+    match (objective, print_data(data)) {
+        (Objective::WorstCase, _) => println!("Worst Case Cost: {}", strategy.cost().unwrap()),
+        (Objective::Expected, Some(stats)) => {
+            stats.print_expected("optimal", strategy.cost().unwrap() as f32)
+        }
+        (Objective::Expected, None) => (),
+    }
+}
+
+/// Plays the game with a moving number with the tracking AI.
+fn play_drifting(min: i128, max: i128, iteration: u32, drift: i128, limit: u128) {
+    let mut game = DriftingGame::new(min, max, drift).unwrap();
+    let mut strategy = DriftTracking::new(drift);
+    let mut data: Vec<u128> = Vec::new();
+
+    for _ in 0..iteration {
+        game.reset_number();
+        if let Some(guesses) = game.solve(&mut strategy, limit) {
+            data.push(guesses);
+        }
+    }
+
+    println!(
+        "Found the number {} out of {iteration} times within {limit} guesses.",
+        data.len()
+    );
+    if data.is_empty() {
+        return;
+    }
+
+    print_data(data);
+}
 
-            let stats = Statistics::from(data);
+/// Plays twenty questions with the AI asking the most informative questions from the pool.
+fn play_questions(min: i128, max: i128, iteration: u32, pool: Vec<Predicate>) {
+    let mut game = QuestionGame::new(min, max).unwrap();
+    let strategy = match pool.is_empty() {
+        true => InformationGain::new(Predicate::pool(min, max)),
+        false => InformationGain::new(pool),
+    };
+    let mut data: Vec<u128> = Vec::new();
+
+    println!(
+        "First question: {}",
+        strategy.next_question(game.candidates())
+    );
+    for _ in 0..iteration {
+        game.reset_number();
+        data.push(game.solve(&strategy));
+    }
+
+    print_data(data);
+}
+
+/// Prints a histogram and the statistics of the data collected from the AI.
+///
+/// The statistics are returned, or None if there is no data.
+fn print_data(data: Vec<u128>) -> Option<Statistics> {
+    let histogram = Histogram::from_vec(data.clone());
+    histogram.print(Direction::Horizontal);
+
+    let stats = Statistics::try_from(data).ok()?;
+    stats.print();
+
+    Some(stats)
+}
+
+/// Plays the game with each of the strategies, comparing their guesses in one histogram.
+fn play_compare(min: i128, max: i128, iteration: u32, algorithms: &[Algorithm], bars: Bars) {
+    let mut histogram = MultiHistogram::new();
+
+    for algorithm in algorithms {
+        let strategy: Box<dyn Strategy> = match algorithm {
+            Algorithm::Bisection => Box::<Bisection>::default(),
+            Algorithm::RaceOptimal => Box::<RaceOptimal>::default(),
+            Algorithm::Galloping => Box::<Galloping>::default(),
+            Algorithm::BentleyYao => Box::<BentleyYao>::default(),
+        };
+        let mut game = AutoNumberGuesser::with_strategy(min, max, strategy).unwrap();
+        let data: Vec<u128> = (0..iteration).map(|_| game.start(false)).collect();
+
+        if let Ok(stats) = Statistics::try_from(data.clone()) {
+            println!(
+                "{algorithm:?}: mean {}, standard deviation {}",
+                stats.mean(),
+                stats.std()
+            );
+        }
+        histogram.push(&format!("{algorithm:?}"), Histogram::from_vec(data));
+    }
+
+    histogram.print(match bars {
+        Bars::Grouped => Layout::Grouped,
+        Bars::Stacked => Layout::Stacked,
+    });
+}
+
+/// Gets the AI bidders of "The Price is Right".
+fn price_bidders(args: &Price) -> Vec<Bidder> {
+    args.bidders
+        .iter()
+        .map(|x| match x {
+            Bid::Cautious => Bidder::new(Risk::Cautious),
+            Bid::Balanced => Bidder::new(Risk::Balanced),
+            Bid::Bold => Bidder::new(Risk::Bold),
+            Bid::Strategic => Bidder::new(Risk::Strategic),
+        })
+        .collect()
+}
+
+/// Plays "The Price is Right" with a human bidding first against the AI bidders.
+fn play_price_human(min: i128, max: i128, args: &Price) {
+    let bidders = price_bidders(args);
+    let mut game = PriceGame::new(min, max, bidders.len() + 1, args.rounds).unwrap();
+
+    while !game.is_over() {
+        println!("Round {} of {}", game.round() + 1, game.rounds());
+        let (current_min, current_max) = game.get_range();
+        let mut bids = vec![game.get_bid()];
+        for (i, bidder) in bidders.iter().enumerate() {
+            let bid = bidder.bid(current_min, current_max, &bids);
+            println!("AI {} ({:?}) bids {bid}.", i + 1, bidder.risk());
+            bids.push(bid);
+        }
+
+        match game.make_bids(&bids).unwrap() {
+            Some(0) => println!("Your bid of {} wins the round.", bids[0]),
+            Some(x) => println!("AI {x}'s bid of {} wins the round.", bids[x]),
+            None => println!("Every bid went over."),
+        }
+    }
+
+    match game.winner() {
+        Some((0, margin)) => println!("Congratulations!! You win, {margin} under the number."),
+        Some((x, margin)) => println!("AI {x} wins, {margin} under the number."),
+        None => println!("Nobody wins."),
+    }
+}
+
+/// Plays "The Price is Right" between the AI bidders.
+fn play_price(min: i128, max: i128, iteration: u32, args: &Price) {
+    let bidders = price_bidders(args);
+    let results = price::simulate(min, max, args.rounds, &bidders, iteration).unwrap();
+
+    // Winners of each game
+    let names: Vec<String> = bidders
+        .iter()
+        .enumerate()
+        .map(|(i, x)| format!("{}:{:?}", i + 1, x.risk()))
+        .collect();
+    let winners: Vec<&str> = results
+        .iter()
+        .map(|x| x.map_or("None", |(i, _)| names[i].as_str()))
+        .collect();
+    let histogram = Histogram::from_vec(winners);
+    histogram.print(Direction::Horizontal);
+
+    for (i, name) in names.iter().enumerate() {
+        let wins: Vec<u128> = results
+            .iter()
+            .map(|x| x.is_some_and(|(winner, _)| winner == i) as u128)
+            .collect();
+
+        if let Ok(stats) = Statistics::try_from(wins) {
+            println!("Wins of {name}:");
             stats.print();
         }
     }
+
+    let margins: Vec<u128> = results
+        .iter()
+        .flatten()
+        .map(|(_, margin)| *margin)
+        .collect();
+    if let Ok(stats) = Statistics::try_from(margins) {
+        println!("Winning bid under the number:");
+        stats.print();
+    }
+}
+
+/// Plays a race between a human and the opponent.
+fn play_race_human(min: i128, max: i128, opponent: Opponent) {
+    let mut game = RaceGame::new(min, max).unwrap();
+    let mut strategy = race_strategy(opponent);
+    strategy.reset(min, max);
+
+    while game.winner().is_none() {
+        let (low, high) = game.game().consistent_range();
+        if game.player() == 0 || opponent == Opponent::Human {
+            game.get_guess();
+        } else {
+            let guess = strategy.next_guess();
+            println!("The AI guessed {guess}.");
+            let status = game.make_guess(guess);
+            game.handle_guess(guess, status);
+        }
+
+        // The AI sees every answer, but guesses outside the numbers left tell it nothing
+        let (guess, status) = game.last_guess().unwrap();
+        if (low..=high).contains(&guess) {
+            strategy.update(guess, status);
+        }
+    }
+}
+
+/// Races every pair of AI strategies against each other.
+fn play_race(min: i128, max: i128, iteration: u32) {
+    let mut optimal = RaceOptimal::default();
+    optimal.reset(min, max);
+    println!(
+        "The first player wins {:.2}% of the time with optimal play.",
+        optimal.win_probability() * 100.0
+    );
+    if iteration == 0 {
+        return;
+    }
+
+    let opponents = [Opponent::Bisection, Opponent::Optimal];
+    for first in opponents {
+        for second in opponents {
+            let (mut a, mut b) = (race_strategy(first), race_strategy(second));
+            let wins = race::simulate(min, max, iteration, a.as_mut(), b.as_mut()).unwrap();
+
+            println!(
+                "{first:?} (first) vs {second:?} (second): {:.2}% to {:.2}%",
+                wins[0] as f32 / iteration as f32 * 100.0,
+                wins[1] as f32 / iteration as f32 * 100.0
+            );
+        }
+    }
+}
+
+/// Gets the strategy of an AI opponent in a race.
+fn race_strategy(opponent: Opponent) -> Box<dyn Strategy> {
+    match opponent {
+        Opponent::Optimal => Box::<RaceOptimal>::default(),
+        _ => Box::<Bisection>::default(),
+    }
+}
+
+fn validate_question(s: &str) -> Result<String, String> {
+    s.parse::<Predicate>()
+        .map(|_| String::from(s))
+        .map_err(|_| String::from("Invalid question"))
 }
 
 fn validate_range(s: &str) -> Result<String, String> {
@@ -6,6 +6,11 @@ use std::{
 
 use rand::Rng;
 
+use super::{
+    efficiency::{bits_gained, grade, worst_case},
+    knowledge::KnowledgeState,
+};
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -30,6 +35,25 @@ mod tests {
     }
 }
 
+/// The number of hints a player can ask for by default.
+pub const DEFAULT_HINT_BUDGET: u32 = 3;
+
+/// The points taken off the score for every hint.
+pub const HINT_PENALTY: u32 = 10;
+
+/// A hint for the next guess.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hint {
+    /// The optimal next guess.
+    pub guess: i128,
+    /// Current minimum the number can be.
+    pub min: i128,
+    /// Current maximum the number can be.
+    pub max: i128,
+    /// The most guesses left with optimal guesses.
+    pub worst_case: u32,
+}
+
 /// A number guessing game.
 pub struct NumberGuessingGame {
     /// Minimum the number can be.
@@ -40,18 +64,34 @@ pub struct NumberGuessingGame {
     guesses: u128,
     /// The current number.
     number: i128,
+    /// The guesses made and their results, in order, if they are recorded.
+    history: Vec<(i128, Ordering)>,
+    /// Whether the guesses made are recorded in the history.
+    record_history: bool,
+    /// What the guesses made tell about the number.
+    knowledge: KnowledgeState,
+    /// The number of hints given.
+    hints: u32,
+    /// The most hints that can be given.
+    hint_budget: u32,
 }
 
 impl NumberGuessingGame {
     /// Creates a number guessing game struct.
     ///
     /// An Err would be returned if min > max.
+    #[allow(clippy::result_unit_err)]
     pub fn new(min: i128, max: i128) -> Result<Self, ()> {
         let mut output = Self {
             min: 0,
             max: 0,
             guesses: 0,
             number: 0,
+            history: Vec::new(),
+            record_history: false,
+            knowledge: KnowledgeState::default(),
+            hints: 0,
+            hint_budget: DEFAULT_HINT_BUDGET,
         };
 
         output.set_range(min, max)?;
@@ -68,6 +108,11 @@ impl NumberGuessingGame {
         self.guesses += 1;
 
         let result = guess.cmp(&self.number);
+        if self.record_history {
+            self.history.push((guess, result));
+        }
+        // The answers always agree as they come from the number
+        let _ = self.knowledge.observe(guess, result);
         guess_handler(result, self.guesses, self.number);
 
         result
@@ -75,7 +120,11 @@ impl NumberGuessingGame {
 
     /// Guess the number from standard input.
     ///
+    /// Typing `hint` gives a hint instead of guessing, while the hint budget
+    /// lasts. Every guess is graded by the bits of information it gained.
+    ///
     /// `true` would be returned if the guess is correct, `false` would be returned otherwise.
+    #[allow(clippy::match_like_matches_macro)]
     pub fn get_guess(&mut self) -> bool {
         loop {
             // Getting the guess from stdin
@@ -89,6 +138,22 @@ impl NumberGuessingGame {
                 .read_line(&mut guess)
                 .expect("Unable to read line.");
 
+            // Giving a hint
+            if guess.trim().eq_ignore_ascii_case("hint") {
+                match self.hint() {
+                    Ok(hint) => println!(
+                        "The number is from {} to {}. Try {}, which finds it within {} guesses. {} hints left.",
+                        hint.min,
+                        hint.max,
+                        hint.guess,
+                        hint.worst_case,
+                        self.hint_budget - self.hints
+                    ),
+                    Err(_) => println!("There are no hints left."),
+                }
+                continue;
+            }
+
             // Parsing Guess
             let guess: i128 = match guess.trim().parse() {
                 Ok(num) => num,
@@ -98,7 +163,17 @@ impl NumberGuessingGame {
                 }
             };
 
+            let before = self.knowledge.size();
             let status = self.make_guess(guess, Self::handle_guess);
+            // A guess with only one number left had no choice to grade
+            if before > 1 {
+                let bits = bits_gained(before, self.knowledge.size());
+                println!("Information gained: {bits:.2} bits ({})", grade(bits));
+            }
+
+            if status == Ordering::Equal {
+                println!("Score: {}", self.score());
+            }
 
             // Returning Guess result
             return match status {
@@ -108,6 +183,66 @@ impl NumberGuessingGame {
         }
     }
 
+    /// Gets the range the number can be in from the guesses made.
+    ///
+    /// This function returns a tuple in the form (minimum, maximum) inclusive of the number.
+    pub fn consistent_range(&self) -> (i128, i128) {
+        self.knowledge
+            .bounds()
+            .unwrap_or((self.number, self.number))
+    }
+
+    /// Gets what the guesses made tell about the number.
+    pub fn knowledge(&self) -> &KnowledgeState {
+        &self.knowledge
+    }
+
+    /// Gives a hint for the next guess.
+    ///
+    /// An Err would be returned if the hint budget has been used up.
+    #[allow(clippy::result_unit_err)]
+    pub fn hint(&mut self) -> Result<Hint, ()> {
+        if self.hints >= self.hint_budget {
+            return Err(());
+        }
+        self.hints += 1;
+
+        let (min, max) = self.consistent_range();
+
+        Ok(Hint {
+            guess: self.knowledge.candidates().median().unwrap_or(min),
+            min,
+            max,
+            worst_case: worst_case(self.knowledge.size()),
+        })
+    }
+
+    /// Gets the score out of 100 for the guesses made.
+    ///
+    /// The score is the optimal number of guesses in the worst case over the
+    /// guesses made, with [`HINT_PENALTY`] taken off for every hint.
+    pub fn score(&self) -> u32 {
+        let optimal = worst_case(self.max.abs_diff(self.min).saturating_add(1)) as u128;
+        let score = (100 * optimal / self.guesses.max(1)).min(100) as u32;
+
+        score.saturating_sub(self.hints * HINT_PENALTY)
+    }
+
+    /// Gets the number of hints given.
+    pub fn get_n_hints(&self) -> u32 {
+        self.hints
+    }
+
+    /// Gets the most hints that can be given.
+    pub fn hint_budget(&self) -> u32 {
+        self.hint_budget
+    }
+
+    /// Sets the most hints that can be given.
+    pub fn set_hint_budget(&mut self, budget: u32) {
+        self.hint_budget = budget;
+    }
+
     /// Resets the number currently stored.
     pub fn reset_number(&mut self) {
         let mut rng = rand::thread_rng();
@@ -117,9 +252,28 @@ impl NumberGuessingGame {
         self.reset_n_guesses();
     }
 
+    /// Sets the number to guess.
+    ///
+    /// An Err would be returned if the number is not within the range. If this
+    /// happens the number would not change.
+    #[allow(clippy::result_unit_err)]
+    pub fn set_number(&mut self, number: i128) -> Result<(), ()> {
+        if number < self.min || number > self.max {
+            return Err(());
+        }
+
+        self.number = number;
+        self.reset_n_guesses();
+
+        Ok(())
+    }
+
     /// Resets the number of guesses made.
     fn reset_n_guesses(&mut self) {
         self.guesses = 0;
+        self.history.clear();
+        self.knowledge = KnowledgeState::new(self.min, self.max);
+        self.hints = 0;
     }
 
     /// Gets the number of guesses made.
@@ -127,6 +281,21 @@ impl NumberGuessingGame {
         self.guesses
     }
 
+    /// Gets the guesses made since the last reset and their results.
+    ///
+    /// The history is empty unless recording it is turned on with
+    /// [`set_record_history`](Self::set_record_history).
+    pub fn history(&self) -> &[(i128, Ordering)] {
+        &self.history
+    }
+
+    /// Sets whether the guesses made are recorded in the history.
+    ///
+    /// Recording is off by default, so games played many times don't keep every guess.
+    pub fn set_record_history(&mut self, record: bool) {
+        self.record_history = record;
+    }
+
     /// Prints the result of make_guess() in a human readable form.
     fn handle_guess(result: Ordering, guesses: u128, number: i128) {
         match result {
@@ -145,6 +314,7 @@ impl NumberGuessingGame {
     ///
     /// An Err would be returned if min is greater than max. If this happens
     /// the number would not change.
+    #[allow(clippy::result_unit_err)]
     pub fn set_min(&mut self, min: i128) -> Result<(), ()> {
         self.set_range(min, self.max)
     }
@@ -158,6 +328,7 @@ impl NumberGuessingGame {
     ///
     /// An Err would be returned if min is greater than max. If this happens
     /// the number would not change.
+    #[allow(clippy::result_unit_err)]
     pub fn set_max(&mut self, max: i128) -> Result<(), ()> {
         self.set_range(self.min, max)
     }
@@ -173,6 +344,7 @@ impl NumberGuessingGame {
     ///
     /// An Err would be returned if min is greater than max. If this happens
     /// the number would not change.
+    #[allow(clippy::result_unit_err)]
     pub fn set_range(&mut self, min: i128, max: i128) -> Result<(), ()> {
         // Checking Range
         if min > max {
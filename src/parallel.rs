@@ -0,0 +1,192 @@
+//! Number guessing game where several guesses are made each round.
+use std::cmp::Ordering;
+
+use super::{knowledge::KnowledgeState, NumberGuessingGame};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split() {
+        // Every number is guessed when there are enough guesses
+        assert_eq!(KarySearch::split(1, 3, 5), vec![1, 2, 3]);
+        // Bisection
+        assert_eq!(KarySearch::split(1, 9, 1), vec![5]);
+        // Splits into k + 1 parts
+        assert_eq!(KarySearch::split(1, 11, 2), vec![4, 8]);
+        assert_eq!(KarySearch::split(0, 14, 4), vec![3, 6, 9, 12]);
+        // Ranges wider than i128::MAX
+        let guesses = KarySearch::split(i128::MIN, i128::MAX, 2);
+        assert!(guesses[0] < guesses[1]);
+        assert!(guesses[1] > 0);
+    }
+}
+
+/// A number guessing game where up to k guesses are made each round.
+///
+/// The result of every guess in a round is given at the same time.
+pub struct ParallelGuessingGame {
+    /// The underlying game.
+    game: NumberGuessingGame,
+    /// Maximum guesses each round.
+    k: usize,
+    /// The number of rounds played.
+    rounds: u128,
+}
+
+impl ParallelGuessingGame {
+    /// Creates a number guessing game with up to k guesses each round.
+    ///
+    /// An Err would be returned if min > max or k is 0.
+    #[allow(clippy::result_unit_err)]
+    pub fn new(min: i128, max: i128, k: usize) -> Result<Self, ()> {
+        if k == 0 {
+            return Err(());
+        }
+
+        let game = NumberGuessingGame::new(min, max)?;
+
+        Ok(Self { game, k, rounds: 0 })
+    }
+
+    /// Makes a round of guesses of the generated number.
+    ///
+    /// The results are given in the same order as the guesses. An Err would be
+    /// returned if there are no guesses or more than k guesses.
+    #[allow(clippy::result_unit_err)]
+    pub fn make_guesses(&mut self, guesses: &[i128]) -> Result<Vec<Ordering>, ()> {
+        if guesses.is_empty() || guesses.len() > self.k {
+            return Err(());
+        }
+
+        self.rounds += 1;
+
+        Ok(guesses
+            .iter()
+            .map(|x| self.game.make_guess(*x, |_, _, _| ()))
+            .collect())
+    }
+
+    /// Guesses the number currently stored using a strategy.
+    ///
+    /// The number of rounds played is returned.
+    pub fn solve(&mut self, strategy: &mut dyn ParallelStrategy) -> u128 {
+        let (min, max) = self.game.get_range();
+        strategy.reset(min, max);
+
+        loop {
+            let guesses = strategy.next_guesses(self.k);
+            let results = self.make_guesses(&guesses).unwrap();
+
+            let results: Vec<(i128, Ordering)> = guesses.into_iter().zip(results).collect();
+            strategy.update(&results);
+            if results.iter().any(|x| x.1 == Ordering::Equal) {
+                break;
+            }
+        }
+
+        self.rounds
+    }
+
+    /// Resets the number currently stored.
+    pub fn reset_number(&mut self) {
+        self.game.reset_number();
+        self.rounds = 0;
+    }
+
+    /// Sets the number to guess.
+    ///
+    /// An Err would be returned if the number is not within the range. If this
+    /// happens the number would not change.
+    #[allow(clippy::result_unit_err)]
+    pub fn set_number(&mut self, number: i128) -> Result<(), ()> {
+        self.game.set_number(number)?;
+        self.rounds = 0;
+
+        Ok(())
+    }
+
+    /// Gets the number of rounds played.
+    pub fn get_n_rounds(&self) -> u128 {
+        self.rounds
+    }
+
+    /// Gets the number of guesses made over all rounds.
+    pub fn get_n_guesses(&self) -> u128 {
+        self.game.get_n_guesses()
+    }
+
+    /// Gets the maximum number of guesses each round.
+    pub fn k(&self) -> usize {
+        self.k
+    }
+
+    /// Returns a reference to the NumberGuessingGame.
+    pub fn game(&self) -> &NumberGuessingGame {
+        &self.game
+    }
+
+    /// Returns a mutable reference to the NumberGuessingGame.
+    pub fn game_mut(&mut self) -> &mut NumberGuessingGame {
+        &mut self.game
+    }
+}
+
+/// A strategy that picks several guesses each round.
+pub trait ParallelStrategy {
+    /// Prepares the strategy for a new game where the number is in `min..=max`.
+    fn reset(&mut self, min: i128, max: i128);
+
+    /// Gets the guesses to make this round, at most `k` guesses.
+    fn next_guesses(&mut self, k: usize) -> Vec<i128>;
+
+    /// Updates the strategy with the result of every guess in a round.
+    fn update(&mut self, results: &[(i128, Ordering)]);
+}
+
+/// k-ary search, splitting the remaining range into k + 1 equal parts each round.
+#[derive(Debug, Default)]
+pub struct KarySearch {
+    /// What the answers so far tell about the number.
+    knowledge: KnowledgeState,
+}
+
+impl KarySearch {
+    /// Gets at most k guesses that split `min..=max` into k + 1 equal parts.
+    fn split(min: i128, max: i128, k: usize) -> Vec<i128> {
+        let size = max.abs_diff(min).saturating_add(1);
+        let parts = k as u128 + 1;
+
+        if size <= k as u128 {
+            return (min..=max).collect();
+        }
+
+        // The offsets are at most max - min, so they can go over i128::MAX but
+        // never past max
+        (1..parts)
+            .map(|i| size / parts * i + size % parts * i / parts)
+            .map(|offset| min.checked_add_unsigned(offset).unwrap())
+            .collect()
+    }
+}
+
+impl ParallelStrategy for KarySearch {
+    fn reset(&mut self, min: i128, max: i128) {
+        self.knowledge = KnowledgeState::new(min, max);
+    }
+
+    fn next_guesses(&mut self, k: usize) -> Vec<i128> {
+        match self.knowledge.bounds() {
+            Some((min, max)) => Self::split(min, max, k),
+            None => Vec::new(),
+        }
+    }
+
+    fn update(&mut self, results: &[(i128, Ordering)]) {
+        for (guess, result) in results {
+            // A contradicting answer is ignored
+            let _ = self.knowledge.observe(*guess, *result);
+        }
+    }
+}
@@ -0,0 +1,256 @@
+//! SVG charts of histograms and benchmark results.
+//!
+//! The charts are written as plain SVG text, so they can be opened in a
+//! browser without any plotting tools.
+use std::{fmt::Display, hash::Hash};
+
+use super::histogram::Histogram;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ticks() {
+        assert_eq!(ticks(0.0, 10.0), vec![0.0, 2.0, 4.0, 6.0, 8.0, 10.0]);
+        assert_eq!(ticks(0.0, 7.0), vec![0.0, 2.0, 4.0, 6.0, 8.0]);
+        assert_eq!(
+            ticks(0.0, 450.0),
+            vec![0.0, 100.0, 200.0, 300.0, 400.0, 500.0]
+        );
+        // Empty data still has an axis
+        assert_eq!(ticks(0.0, 0.0).last(), Some(&1.0));
+    }
+}
+
+/// The width of a chart in pixels.
+pub const WIDTH: f64 = 640.0;
+
+/// The height of a chart in pixels.
+pub const HEIGHT: f64 = 400.0;
+
+/// The space around the plot area for the title, axes and labels, in the
+/// form (top, right, bottom, left).
+const MARGIN: (f64, f64, f64, f64) = (40.0, 20.0, 50.0, 60.0);
+
+/// The colours of the series, in order.
+const COLOURS: [&str; 6] = [
+    "#1f77b4", "#ff7f0e", "#2ca02c", "#d62728", "#9467bd", "#8c564b",
+];
+
+/// A series of benchmark results to plot.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Series {
+    /// The name of the series, shown in the legend.
+    pub name: String,
+    /// The points in the form (x, mean, standard deviation).
+    pub points: Vec<(f64, f64, f64)>,
+}
+
+impl Series {
+    /// Creates an empty series.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            points: Vec::new(),
+        }
+    }
+
+    /// Adds a point to the series.
+    pub fn push(&mut self, x: f64, mean: f64, std: f64) {
+        self.points.push((x, mean, std));
+    }
+}
+
+/// Draws a bar chart of a histogram.
+pub fn histogram<T>(histogram: &Histogram<T>, title: &str) -> String
+where
+    T: Eq + Hash + Display + Ord,
+{
+    let data = histogram.data();
+    let highest = data.values().max().copied().unwrap_or(0) as f64;
+    let y_ticks = ticks(0.0, highest);
+    let y_max = *y_ticks.last().unwrap();
+
+    let (top, right, bottom, left) = MARGIN;
+    let (width, height) = (WIDTH - left - right, HEIGHT - top - bottom);
+    let slot = width / data.len().max(1) as f64;
+    // Labelling every nth bar so the labels don't overlap
+    let every = (data.len() as f64 * 40.0 / width).ceil().max(1.0) as usize;
+
+    let mut output = header(title);
+    output.push_str(&y_axis(&y_ticks, "Count"));
+    for (i, (key, value)) in data.iter().enumerate() {
+        let bar = height * *value as f64 / y_max;
+        let x = left + slot * i as f64;
+
+        output.push_str(&format!(
+            "<rect x=\"{:.1}\" y=\"{:.1}\" width=\"{:.1}\" height=\"{bar:.1}\" fill=\"{}\"><title>{}: {value}</title></rect>\n",
+            x + slot * 0.1,
+            top + height - bar,
+            slot * 0.8,
+            COLOURS[0],
+            escape(&key.to_string())
+        ));
+        if i % every == 0 {
+            output.push_str(&text(
+                x + slot / 2.0,
+                top + height + 16.0,
+                "middle",
+                &key.to_string(),
+            ));
+        }
+    }
+    output.push_str("</svg>\n");
+
+    output
+}
+
+/// Draws a line chart of the mean of every series, with a band of one
+/// standard deviation around it.
+pub fn series(series: &[Series], title: &str, x_label: &str, y_label: &str) -> String {
+    let points = series.iter().flat_map(|x| x.points.iter());
+    let (x_min, x_max) = points
+        .clone()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), x| {
+            (min.min(x.0), max.max(x.0))
+        });
+    let (x_min, x_max) = match x_min <= x_max {
+        true => (x_min, x_max),
+        false => (0.0, 1.0),
+    };
+    let y_high = points.fold(0.0, |max: f64, x| max.max(x.1 + x.2));
+    let y_ticks = ticks(0.0, y_high);
+    let y_max = *y_ticks.last().unwrap();
+
+    let (top, right, bottom, left) = MARGIN;
+    let (width, height) = (WIDTH - left - right, HEIGHT - top - bottom);
+    let x_pos = |x: f64| left + width * (x - x_min) / (x_max - x_min).max(f64::EPSILON);
+    let y_pos = |y: f64| top + height - height * y.max(0.0) / y_max;
+
+    let mut output = header(title);
+    output.push_str(&y_axis(&y_ticks, y_label));
+    for x in [x_min, (x_min + x_max) / 2.0, x_max] {
+        output.push_str(&text(
+            x_pos(x),
+            top + height + 16.0,
+            "middle",
+            &format!("{x}"),
+        ));
+    }
+    output.push_str(&text(left + width / 2.0, HEIGHT - 10.0, "middle", x_label));
+
+    for (i, series) in series.iter().enumerate() {
+        let colour = COLOURS[i % COLOURS.len()];
+        let line = |f: &dyn Fn(&(f64, f64, f64)) -> f64| -> Vec<String> {
+            series
+                .points
+                .iter()
+                .map(|x| format!("{:.1},{:.1}", x_pos(x.0), y_pos(f(x))))
+                .collect()
+        };
+
+        // The band goes along the top then back along the bottom
+        let mut band = line(&|x| x.1 + x.2);
+        band.extend(line(&|x| x.1 - x.2).into_iter().rev());
+        output.push_str(&format!(
+            "<polygon points=\"{}\" fill=\"{colour}\" fill-opacity=\"0.2\"/>\n",
+            band.join(" ")
+        ));
+        output.push_str(&format!(
+            "<polyline points=\"{}\" fill=\"none\" stroke=\"{colour}\" stroke-width=\"2\"/>\n",
+            line(&|x| x.1).join(" ")
+        ));
+
+        // Legend
+        let y = top + 16.0 * i as f64;
+        output.push_str(&format!(
+            "<rect x=\"{:.1}\" y=\"{:.1}\" width=\"12\" height=\"12\" fill=\"{colour}\"/>\n",
+            left + 10.0,
+            y
+        ));
+        output.push_str(&text(left + 28.0, y + 10.0, "start", &series.name));
+    }
+    output.push_str("</svg>\n");
+
+    output
+}
+
+/// Gets the start of an SVG chart with the title.
+fn header(title: &str) -> String {
+    let mut output = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{WIDTH}\" height=\"{HEIGHT}\" viewBox=\"0 0 {WIDTH} {HEIGHT}\" font-family=\"sans-serif\" font-size=\"12\">\n"
+    );
+    output.push_str("<rect width=\"100%\" height=\"100%\" fill=\"white\"/>\n");
+    output.push_str(&format!(
+        "<text x=\"{}\" y=\"24\" text-anchor=\"middle\" font-size=\"16\">{}</text>\n",
+        WIDTH / 2.0,
+        escape(title)
+    ));
+
+    output
+}
+
+/// Gets the axes with ticks going up the y axis, and the label of the y axis.
+fn y_axis(ticks: &[f64], label: &str) -> String {
+    let (top, right, bottom, left) = MARGIN;
+    let height = HEIGHT - top - bottom;
+    let max = *ticks.last().unwrap();
+
+    let mut output = String::new();
+    for tick in ticks {
+        let y = top + height - height * tick / max;
+        output.push_str(&format!(
+            "<line x1=\"{left}\" y1=\"{y:.1}\" x2=\"{}\" y2=\"{y:.1}\" stroke=\"#ddd\"/>\n",
+            WIDTH - right
+        ));
+        // Rounding away float errors from adding up the steps
+        let tick = (tick * 1e6).round() / 1e6;
+        output.push_str(&text(left - 6.0, y + 4.0, "end", &format!("{tick}")));
+    }
+    output.push_str(&format!(
+        "<polyline points=\"{left},{top} {left},{} {},{}\" fill=\"none\" stroke=\"black\"/>\n",
+        top + height,
+        WIDTH - right,
+        top + height
+    ));
+    output.push_str(&format!(
+        "<text transform=\"translate(16 {:.1}) rotate(-90)\" text-anchor=\"middle\">{}</text>\n",
+        top + height / 2.0,
+        escape(label)
+    ));
+
+    output
+}
+
+/// Gets a text element at the position.
+fn text(x: f64, y: f64, anchor: &str, content: &str) -> String {
+    format!(
+        "<text x=\"{x:.1}\" y=\"{y:.1}\" text-anchor=\"{anchor}\">{}</text>\n",
+        escape(content)
+    )
+}
+
+/// Escapes the characters with a special meaning in SVG.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Gets about 5 evenly spaced round ticks from min, reaching at least max.
+fn ticks(min: f64, max: f64) -> Vec<f64> {
+    let range = (max - min).max(1.0);
+    let rough = range / 5.0;
+    let magnitude = 10f64.powf(rough.log10().floor());
+    // The step is 1, 2, 5 or 10 times a power of 10
+    let step = [1.0, 2.0, 5.0, 10.0]
+        .into_iter()
+        .map(|x| x * magnitude)
+        .find(|x| *x >= rough)
+        .unwrap();
+    let count = (range / step).ceil() as usize;
+
+    (0..=count).map(|i| min + i as f64 * step).collect()
+}
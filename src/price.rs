@@ -0,0 +1,278 @@
+//! Number guessing game where the closest bid without going over wins, like "The Price is Right".
+//!
+//! Each round every player bids once, in turn, seeing the bids made before. The
+//! closest bid that isn't more than the number wins the round. After a round the
+//! range narrows to between the winning bid and the lowest bid that went over,
+//! and the winner of the last round wins the game. A bid of the number wins
+//! straight away.
+use std::cmp::Ordering;
+
+use super::{input, NumberGuessingGame};
+
+/// A "The Price is Right" game between a number of players.
+pub struct PriceGame {
+    /// The underlying game.
+    game: NumberGuessingGame,
+    /// The number of players.
+    players: usize,
+    /// The number of rounds.
+    rounds: u32,
+    /// The number of rounds played.
+    round: u32,
+    /// Current minimum the number can be.
+    current_min: i128,
+    /// Current maximum the number can be.
+    current_max: i128,
+    /// The player who won the last round and how far under the number the bid was.
+    winner: Option<(usize, u128)>,
+    /// Whether a bid was the number.
+    exact: bool,
+}
+
+impl PriceGame {
+    /// Creates a game for the number of players lasting the number of rounds.
+    ///
+    /// An Err would be returned if min > max, or there are 0 players or rounds.
+    #[allow(clippy::result_unit_err)]
+    pub fn new(min: i128, max: i128, players: usize, rounds: u32) -> Result<Self, ()> {
+        if players == 0 || rounds == 0 {
+            return Err(());
+        }
+
+        let game = NumberGuessingGame::new(min, max)?;
+
+        Ok(Self {
+            game,
+            players,
+            rounds,
+            round: 0,
+            current_min: min,
+            current_max: max,
+            winner: None,
+            exact: false,
+        })
+    }
+
+    /// Gets the player with the closest bid without going over the number.
+    ///
+    /// If several players bid the same, the first of them wins. None would be
+    /// returned if every bid went over.
+    pub fn score(bids: &[i128], number: i128) -> Option<usize> {
+        bids.iter()
+            .enumerate()
+            .filter(|(_, bid)| **bid <= number)
+            .min_by_key(|(i, bid)| (number.abs_diff(**bid), *i))
+            .map(|(i, _)| i)
+    }
+
+    /// Plays a round with a bid from every player, in the order the players bid.
+    ///
+    /// The winner of the round is returned. An Err would be returned if the game
+    /// is over or there isn't a bid from every player.
+    #[allow(clippy::result_unit_err)]
+    pub fn make_bids(&mut self, bids: &[i128]) -> Result<Option<usize>, ()> {
+        if self.is_over() || bids.len() != self.players {
+            return Err(());
+        }
+
+        let mut number = 0;
+        let mut results = Vec::with_capacity(bids.len());
+        for bid in bids {
+            results.push(self.game.make_guess(*bid, |_, _, x| number = x));
+        }
+        self.round += 1;
+
+        let winner = Self::score(bids, number);
+        self.winner = winner.map(|x| (x, number.abs_diff(bids[x])));
+        self.exact = results.contains(&Ordering::Equal);
+
+        // Narrowing the range
+        for (bid, result) in bids.iter().zip(results) {
+            match result {
+                Ordering::Greater => self.current_max = self.current_max.min(bid - 1),
+                _ => self.current_min = self.current_min.max(*bid),
+            }
+        }
+
+        Ok(winner)
+    }
+
+    /// Gets a bid from standard input, within the current range.
+    pub fn get_bid(&self) -> i128 {
+        // Getting the bid from stdin
+        input::read_number(&format!(
+            "Your bid ({}-{}): ",
+            self.current_min, self.current_max
+        ))
+    }
+
+    /// Returns `true` if every round has been played or a bid was the number.
+    pub fn is_over(&self) -> bool {
+        self.exact || self.round == self.rounds
+    }
+
+    /// Gets the player who won the last round and how far under the number the bid was.
+    pub fn winner(&self) -> Option<(usize, u128)> {
+        self.winner
+    }
+
+    /// Resets the number currently stored and the rounds played.
+    pub fn reset_number(&mut self) {
+        self.game.reset_number();
+        self.reset_rounds();
+    }
+
+    /// Sets the number to guess and resets the rounds played.
+    ///
+    /// An Err would be returned if the number is not within the range. If this
+    /// happens the number would not change.
+    #[allow(clippy::result_unit_err)]
+    pub fn set_number(&mut self, number: i128) -> Result<(), ()> {
+        self.game.set_number(number)?;
+        self.reset_rounds();
+
+        Ok(())
+    }
+
+    /// Resets the rounds played and the range.
+    fn reset_rounds(&mut self) {
+        let (min, max) = self.game.get_range();
+        self.round = 0;
+        self.current_min = min;
+        self.current_max = max;
+        self.winner = None;
+        self.exact = false;
+    }
+
+    /// Gets the range the number can be in from the bids so far.
+    ///
+    /// This function returns a tuple in the form (minimum, maximum) inclusive of the number.
+    pub fn get_range(&self) -> (i128, i128) {
+        (self.current_min, self.current_max)
+    }
+
+    /// Gets the number of rounds played.
+    pub fn round(&self) -> u32 {
+        self.round
+    }
+
+    /// Gets the number of rounds.
+    pub fn rounds(&self) -> u32 {
+        self.rounds
+    }
+
+    /// Gets the number of players.
+    pub fn players(&self) -> usize {
+        self.players
+    }
+
+    /// Returns a reference to the NumberGuessingGame.
+    pub fn game(&self) -> &NumberGuessingGame {
+        &self.game
+    }
+}
+
+/// How an AI bidder bids.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Risk {
+    /// Bids a quarter of the way into the range, rarely going over.
+    Cautious,
+    /// Bids the middle of the range.
+    Balanced,
+    /// Bids three quarters of the way into the range, often going over.
+    Bold,
+    /// Bids just above another bid, taking the widest gap the bids before leave.
+    Strategic,
+}
+
+/// An AI bidder with a risk profile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bidder {
+    /// How the bidder bids.
+    risk: Risk,
+}
+
+impl Bidder {
+    /// Creates a bidder with a risk profile.
+    pub fn new(risk: Risk) -> Self {
+        Self { risk }
+    }
+
+    /// Gets the risk profile of the bidder.
+    pub fn risk(&self) -> Risk {
+        self.risk
+    }
+
+    /// Gets the bid for the range `min..=max`, after the bids made before in the round.
+    pub fn bid(&self, min: i128, max: i128, bids: &[i128]) -> i128 {
+        // The size is split before multiplying so the full range doesn't overflow
+        let size = max.abs_diff(min);
+        let quantile = |x: u128| {
+            min.checked_add_unsigned(size / 4 * x + size % 4 * x / 4)
+                .unwrap_or(max)
+        };
+
+        match self.risk {
+            Risk::Cautious => quantile(1),
+            Risk::Balanced => quantile(2),
+            Risk::Bold => quantile(3),
+            Risk::Strategic => {
+                // A bid wins when the number is from it to the next higher bid,
+                // and loses to an earlier bid of the same
+                let options = bids
+                    .iter()
+                    .filter(|x| (min..max).contains(*x))
+                    .map(|x| x + 1)
+                    .chain([min])
+                    .filter(|x| !bids.contains(x));
+
+                let wins = |bid: i128| match bids.iter().filter(|x| **x > bid).min() {
+                    Some(next) if *next <= max => next.abs_diff(bid),
+                    // Up to the maximum, which may be the largest number there is
+                    _ => max.abs_diff(bid).saturating_add(1),
+                };
+                options.max_by_key(|x| (wins(*x), *x)).unwrap_or(min)
+            }
+        }
+    }
+}
+
+/// Plays `games` games on `min..=max` between the bidders, each lasting `rounds` rounds.
+///
+/// The first bidder changes every game so every bidder bids first as often.
+/// The winner of each game and how far under the number the winning bid was
+/// is returned, or None if nobody won.
+/// An Err would be returned if min > max, or there are 0 bidders or rounds.
+#[allow(clippy::result_unit_err)]
+pub fn simulate(
+    min: i128,
+    max: i128,
+    rounds: u32,
+    bidders: &[Bidder],
+    games: u32,
+) -> Result<Vec<Option<(usize, u128)>>, ()> {
+    let mut game = PriceGame::new(min, max, bidders.len(), rounds)?;
+    let mut results = Vec::with_capacity(games as usize);
+
+    for i in 0..games {
+        game.reset_number();
+        // The bidders in the order they bid
+        let order: Vec<usize> = (0..bidders.len())
+            .map(|x| (x + i as usize) % bidders.len())
+            .collect();
+
+        while !game.is_over() {
+            let (current_min, current_max) = game.get_range();
+            let mut bids = Vec::with_capacity(order.len());
+            for bidder in &order {
+                let bid = bidders[*bidder].bid(current_min, current_max, &bids);
+                bids.push(bid);
+            }
+            game.make_bids(&bids).unwrap();
+        }
+
+        results.push(game.winner().map(|(x, margin)| (order[x], margin)));
+    }
+
+    Ok(results)
+}
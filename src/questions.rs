@@ -0,0 +1,594 @@
+//! Number guessing game where any yes or no question can be asked, like twenty questions.
+//!
+//! Questions are written in a small query language:
+//! - `even?`, `odd?`, `prime?` and `square?`
+//! - `x in 10..40` or `x in 10..=40`
+//! - a comparison of `x`, `x % m` or `digit_sum` with a number using `==`,
+//!   `!=`, `<`, `<=`, `>` or `>=`, such as `x % 7 == 3` or `digit_sum > 10`
+//!
+//! The game keeps the numbers that agree with every answer so far. It is over
+//! when only the number is left.
+use std::{fmt, str::FromStr};
+
+use rand::Rng;
+
+use super::input;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("even?".parse(), Ok(Predicate::Even));
+        assert_eq!(" Prime? ".parse(), Ok(Predicate::Prime));
+        assert_eq!(
+            "x % 7 == 3".parse(),
+            Ok(Predicate::Compare(Term::Modulo(7), Comparison::Equal, 3))
+        );
+        assert_eq!(
+            "x%7==3".parse(),
+            Ok(Predicate::Compare(Term::Modulo(7), Comparison::Equal, 3))
+        );
+        assert_eq!(
+            "digit_sum > 10".parse(),
+            Ok(Predicate::Compare(Term::DigitSum, Comparison::Greater, 10))
+        );
+        assert_eq!(
+            "x <= -5".parse(),
+            Ok(Predicate::Compare(Term::Number, Comparison::LessEqual, -5))
+        );
+        assert_eq!("x in 10..40".parse(), Ok(Predicate::In(10, 39)));
+        assert_eq!("x in 10..=40".parse(), Ok(Predicate::In(10, 40)));
+
+        // Invalid questions
+        assert_eq!("odd".parse::<Predicate>(), Err(()));
+        assert_eq!("x % 0 == 1".parse::<Predicate>(), Err(()));
+        assert_eq!("y > 3".parse::<Predicate>(), Err(()));
+        assert_eq!("x in 10".parse::<Predicate>(), Err(()));
+        assert_eq!(
+            format!("x in 0..{}", i128::MIN).parse::<Predicate>(),
+            Err(())
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        for question in ["even?", "x % 7 == 3", "digit_sum > 10", "x in 10..=40"] {
+            let predicate: Predicate = question.parse().unwrap();
+            assert_eq!(predicate.to_string(), question);
+        }
+    }
+
+    #[test]
+    fn test_is_prime() {
+        let primes: Vec<i128> = (-10..50).filter(|x| is_prime(*x)).collect();
+        assert_eq!(
+            primes,
+            vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47]
+        );
+
+        // Strong pseudoprimes to the first few bases
+        assert!(!is_prime(3_215_031_751));
+        assert!(!is_prime(3_825_123_056_546_413_051));
+        assert!(!is_prime(318_665_857_834_031_151_167_461));
+        // The largest primes below 2^64 and 2^127
+        assert!(is_prime(18_446_744_073_709_551_557));
+        assert!(is_prime(i128::MAX));
+        assert!(!is_prime(i128::MAX - 1));
+    }
+}
+
+/// The value of the number a question compares.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Term {
+    /// The number itself, `x`.
+    Number,
+    /// The remainder of the number divided by the value, `x % m`.
+    Modulo(i128),
+    /// The sum of the digits of the number, `digit_sum`.
+    DigitSum,
+}
+
+impl Term {
+    /// Gets the value of the term for a number.
+    pub fn evaluate(&self, number: i128) -> i128 {
+        match self {
+            Term::Number => number,
+            Term::Modulo(m) => number.rem_euclid(*m),
+            Term::DigitSum => digit_sum(number),
+        }
+    }
+}
+
+/// A comparison between a term and a value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    /// `==`
+    Equal,
+    /// `!=`
+    NotEqual,
+    /// `<`
+    Less,
+    /// `<=`
+    LessEqual,
+    /// `>`
+    Greater,
+    /// `>=`
+    GreaterEqual,
+}
+
+impl Comparison {
+    /// Every comparison with its symbol, with the longer symbols first.
+    const SYMBOLS: [(&'static str, Comparison); 6] = [
+        ("==", Comparison::Equal),
+        ("!=", Comparison::NotEqual),
+        ("<=", Comparison::LessEqual),
+        (">=", Comparison::GreaterEqual),
+        ("<", Comparison::Less),
+        (">", Comparison::Greater),
+    ];
+
+    /// Compares two values.
+    pub fn evaluate(&self, left: i128, right: i128) -> bool {
+        match self {
+            Comparison::Equal => left == right,
+            Comparison::NotEqual => left != right,
+            Comparison::Less => left < right,
+            Comparison::LessEqual => left <= right,
+            Comparison::Greater => left > right,
+            Comparison::GreaterEqual => left >= right,
+        }
+    }
+
+    /// Gets the symbol of the comparison.
+    pub fn symbol(&self) -> &'static str {
+        Self::SYMBOLS.iter().find(|x| x.1 == *self).unwrap().0
+    }
+}
+
+/// A yes or no question about the number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Predicate {
+    /// Is the number even?
+    Even,
+    /// Is the number odd?
+    Odd,
+    /// Is the number prime?
+    Prime,
+    /// Is the number a perfect square?
+    Square,
+    /// Is the term of the number in the comparison with the value?
+    Compare(Term, Comparison, i128),
+    /// Is the number in the range, inclusive of both ends?
+    In(i128, i128),
+}
+
+impl Predicate {
+    /// Gets the answer to the question for a number.
+    pub fn evaluate(&self, number: i128) -> bool {
+        match self {
+            Predicate::Even => number % 2 == 0,
+            Predicate::Odd => number % 2 != 0,
+            Predicate::Prime => is_prime(number),
+            Predicate::Square => is_square(number),
+            Predicate::Compare(term, comparison, value) => {
+                comparison.evaluate(term.evaluate(number), *value)
+            }
+            Predicate::In(a, b) => (*a..=*b).contains(&number),
+        }
+    }
+
+    /// Gets a pool of questions for the range `min..=max`.
+    ///
+    /// The pool has the parity, prime and square questions, remainders of up to
+    /// 10, digit sums and up to 64 evenly spaced `x < v` questions. The prime
+    /// question is left out if the maximum is more than [`MAX_PRIME`].
+    pub fn pool(min: i128, max: i128) -> Vec<Predicate> {
+        let mut pool = vec![Predicate::Even, Predicate::Odd];
+        if max <= MAX_PRIME {
+            pool.push(Predicate::Prime);
+        }
+        pool.push(Predicate::Square);
+
+        for m in 3..=10 {
+            for r in 0..m {
+                pool.push(Predicate::Compare(Term::Modulo(m), Comparison::Equal, r));
+            }
+        }
+
+        let digits = min.unsigned_abs().max(max.unsigned_abs()).to_string().len() as i128;
+        for d in 1..9 * digits {
+            pool.push(Predicate::Compare(Term::DigitSum, Comparison::Greater, d));
+        }
+
+        let size = max.abs_diff(min);
+        let steps = size.min(64);
+        for step in 1..=steps {
+            let offset = size / steps * step + size % steps * step / steps;
+            // The offset is at most max - min
+            let value = min.checked_add_unsigned(offset).unwrap();
+            pool.push(Predicate::Compare(Term::Number, Comparison::Less, value));
+        }
+
+        pool
+    }
+}
+
+impl FromStr for Predicate {
+    type Err = ();
+
+    /// Parses a question written in the query language.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim().to_ascii_lowercase();
+
+        // Properties
+        match s.as_str() {
+            "even?" => return Ok(Predicate::Even),
+            "odd?" => return Ok(Predicate::Odd),
+            "prime?" => return Ok(Predicate::Prime),
+            "square?" => return Ok(Predicate::Square),
+            _ => (),
+        }
+
+        // Ranges
+        if let Some(range) = s.strip_prefix("x in ") {
+            let (start, end, inclusive) = match range.split_once("..=") {
+                Some((start, end)) => (start, end, true),
+                None => {
+                    let (start, end) = range.split_once("..").ok_or(())?;
+                    (start, end, false)
+                }
+            };
+            let start: i128 = start.trim().parse().map_err(|_| ())?;
+            let end: i128 = end.trim().parse().map_err(|_| ())?;
+
+            return match inclusive {
+                true => Ok(Predicate::In(start, end)),
+                false => Ok(Predicate::In(start, end.checked_sub(1).ok_or(())?)),
+            };
+        }
+
+        // Comparisons
+        let (symbol, comparison) = Comparison::SYMBOLS
+            .iter()
+            .find(|x| s.contains(x.0))
+            .ok_or(())?;
+        let (term, value) = s.split_once(symbol).ok_or(())?;
+        let value: i128 = value.trim().parse().map_err(|_| ())?;
+
+        let term: String = term.split_whitespace().collect();
+        let term = match term.as_str() {
+            "x" => Term::Number,
+            "digit_sum" => Term::DigitSum,
+            _ => {
+                let m: i128 = term.strip_prefix("x%").ok_or(())?.parse().map_err(|_| ())?;
+                if m <= 0 {
+                    return Err(());
+                }
+                Term::Modulo(m)
+            }
+        };
+
+        Ok(Predicate::Compare(term, *comparison, value))
+    }
+}
+
+impl fmt::Display for Predicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Predicate::Even => write!(f, "even?"),
+            Predicate::Odd => write!(f, "odd?"),
+            Predicate::Prime => write!(f, "prime?"),
+            Predicate::Square => write!(f, "square?"),
+            Predicate::Compare(term, comparison, value) => {
+                match term {
+                    Term::Number => write!(f, "x")?,
+                    Term::Modulo(m) => write!(f, "x % {m}")?,
+                    Term::DigitSum => write!(f, "digit_sum")?,
+                }
+                write!(f, " {} {value}", comparison.symbol())
+            }
+            Predicate::In(a, b) => write!(f, "x in {a}..={b}"),
+        }
+    }
+}
+
+/// Gets the sum of the digits of a number, ignoring the sign.
+fn digit_sum(number: i128) -> i128 {
+    let mut number = number.unsigned_abs();
+    let mut sum = 0;
+
+    while number > 0 {
+        sum += (number % 10) as i128;
+        number /= 10;
+    }
+
+    sum
+}
+
+/// The largest number `prime?` is always answered correctly for.
+///
+/// Primes are found with the Miller–Rabin test using the primes up to 41 as
+/// bases, which has no false positives below this. Above it, a few composite
+/// numbers could be taken as prime.
+pub const MAX_PRIME: i128 = 3_317_044_064_679_887_385_961_980;
+
+/// The bases of the Miller–Rabin test.
+const PRIME_BASES: [u128; 13] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
+
+/// Returns `true` if the number is prime.
+///
+/// This is exact up to [`MAX_PRIME`].
+fn is_prime(number: i128) -> bool {
+    if number < 2 {
+        return false;
+    }
+
+    let number = number as u128;
+    for base in PRIME_BASES {
+        if number.is_multiple_of(base) {
+            return number == base;
+        }
+    }
+
+    // number - 1 = odd × 2^twos
+    let twos = (number - 1).trailing_zeros();
+    let odd = (number - 1) >> twos;
+
+    'bases: for base in PRIME_BASES {
+        let mut x = pow_mod(base, odd, number);
+        if x == 1 || x == number - 1 {
+            continue;
+        }
+
+        for _ in 1..twos {
+            x = mul_mod(x, x, number);
+            if x == number - 1 {
+                continue 'bases;
+            }
+        }
+
+        return false;
+    }
+
+    true
+}
+
+/// Gets `a × b mod m`, where a and b are less than m, without overflowing.
+fn mul_mod(a: u128, b: u128, m: u128) -> u128 {
+    if a >> 64 == 0 && b >> 64 == 0 {
+        return a * b % m;
+    }
+
+    // Double and add
+    let add = |x: u128, y: u128| if x >= m - y { x - (m - y) } else { x + y };
+    let (mut a, mut b, mut result) = (a, b, 0);
+    while b > 0 {
+        if b & 1 == 1 {
+            result = add(result, a);
+        }
+        a = add(a, a);
+        b >>= 1;
+    }
+
+    result
+}
+
+/// Gets `base^exponent mod m`, where base is less than m.
+fn pow_mod(base: u128, exponent: u128, m: u128) -> u128 {
+    let (mut base, mut exponent, mut result) = (base, exponent, 1);
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod(result, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exponent >>= 1;
+    }
+
+    result
+}
+
+/// Returns `true` if the number is a perfect square.
+fn is_square(number: i128) -> bool {
+    if number < 0 {
+        return false;
+    }
+
+    let root = (number as f64).sqrt() as i128;
+    (root.saturating_sub(1)..=root.saturating_add(1)).any(|x| x.checked_mul(x) == Some(number))
+}
+
+/// The most numbers a [`QuestionGame`] can be played with.
+pub const MAX_CANDIDATES: u128 = 10_000_000;
+
+/// A number guessing game answering yes or no questions.
+///
+/// Every number that agrees with the answers is kept, so the range can have at
+/// most [`MAX_CANDIDATES`] numbers.
+pub struct QuestionGame {
+    /// Minimum the number can be.
+    min: i128,
+    /// Maximum the number can be.
+    max: i128,
+    /// The number of questions asked.
+    questions: u128,
+    /// The current number.
+    number: i128,
+    /// The numbers that agree with the answers so far.
+    candidates: Vec<i128>,
+}
+
+impl QuestionGame {
+    /// Creates a twenty questions game.
+    ///
+    /// An Err would be returned if min > max or there are more than
+    /// [`MAX_CANDIDATES`] numbers in the range.
+    #[allow(clippy::result_unit_err)]
+    pub fn new(min: i128, max: i128) -> Result<Self, ()> {
+        if min > max || max.abs_diff(min) >= MAX_CANDIDATES {
+            return Err(());
+        }
+
+        let mut output = Self {
+            min,
+            max,
+            questions: 0,
+            number: min,
+            candidates: Vec::new(),
+        };
+        output.reset_number();
+
+        Ok(output)
+    }
+
+    /// Asks a question about the number, keeping the numbers that agree with the answer.
+    pub fn ask(&mut self, question: &Predicate) -> bool {
+        // Increasing the amount of questions
+        self.questions += 1;
+
+        let answer = question.evaluate(self.number);
+        self.candidates.retain(|x| question.evaluate(*x) == answer);
+
+        answer
+    }
+
+    /// Returns `true` if only the number agrees with the answers.
+    pub fn is_solved(&self) -> bool {
+        self.candidates.len() == 1
+    }
+
+    /// Asks a question from standard input.
+    ///
+    /// `true` would be returned if the number is found, `false` would be returned otherwise.
+    pub fn get_question(&mut self) -> bool {
+        // Getting the question from stdin
+        let question: Predicate = input::read_parsed("Ask a question: ", "Invalid Question");
+
+        match self.ask(&question) {
+            true => println!("Yes, {question}"),
+            false => println!("No, not {question}"),
+        }
+
+        if self.is_solved() {
+            println!(
+                "Congratulations!! The number is {}. You took {} questions.",
+                self.number, self.questions
+            );
+            return true;
+        }
+        println!("There are {} numbers left.", self.candidates.len());
+
+        false
+    }
+
+    /// Resets the number currently stored.
+    pub fn reset_number(&mut self) {
+        let mut rng = rand::thread_rng();
+        // Resetting Number
+        self.number = rng.gen_range(self.min..=self.max);
+        self.reset_n_questions();
+    }
+
+    /// Sets the number to find.
+    ///
+    /// An Err would be returned if the number is not within the range. If this
+    /// happens the number would not change.
+    #[allow(clippy::result_unit_err)]
+    pub fn set_number(&mut self, number: i128) -> Result<(), ()> {
+        if number < self.min || number > self.max {
+            return Err(());
+        }
+
+        self.number = number;
+        self.reset_n_questions();
+
+        Ok(())
+    }
+
+    /// Resets the number of questions asked and the numbers left.
+    fn reset_n_questions(&mut self) {
+        self.questions = 0;
+        self.candidates = (self.min..=self.max).collect();
+    }
+
+    /// Gets the number of questions asked.
+    pub fn get_n_questions(&self) -> u128 {
+        self.questions
+    }
+
+    /// Gets the numbers that agree with the answers so far.
+    pub fn candidates(&self) -> &[i128] {
+        &self.candidates
+    }
+
+    /// Gets the range the number can be in.
+    ///
+    /// This function returns a tuple in the form (minimum, maximum) inclusive of the number.
+    pub fn get_range(&self) -> (i128, i128) {
+        (self.min, self.max)
+    }
+
+    /// Finds the number by asking the questions chosen by a strategy.
+    ///
+    /// The number of questions asked is returned.
+    pub fn solve(&mut self, strategy: &InformationGain) -> u128 {
+        while !self.is_solved() {
+            let question = strategy.next_question(&self.candidates);
+            self.ask(&question);
+        }
+
+        self.questions
+    }
+}
+
+/// Strategy that asks the question from a pool that gives the most information.
+///
+/// The information of a question is the entropy of its answer when every number
+/// left is equally likely, so the best question splits the numbers left in half.
+/// If no question in the pool splits them, the numbers left are split at the middle.
+#[derive(Debug, Clone)]
+pub struct InformationGain {
+    /// The questions to choose from.
+    pool: Vec<Predicate>,
+}
+
+impl InformationGain {
+    /// Creates a strategy choosing from the pool of questions.
+    pub fn new(pool: Vec<Predicate>) -> Self {
+        Self { pool }
+    }
+
+    /// Gets the questions to choose from.
+    pub fn pool(&self) -> &[Predicate] {
+        &self.pool
+    }
+
+    /// Gets the information in bits of the answer to a question.
+    pub fn information(question: &Predicate, candidates: &[i128]) -> f64 {
+        let yes = candidates.iter().filter(|x| question.evaluate(**x)).count();
+        let p = yes as f64 / candidates.len() as f64;
+
+        if yes == 0 || yes == candidates.len() {
+            return 0.0;
+        }
+        -p * p.log2() - (1.0 - p) * (1.0 - p).log2()
+    }
+
+    /// Gets the question that gives the most information about the numbers left.
+    pub fn next_question(&self, candidates: &[i128]) -> Predicate {
+        let mut best = (0.0, None);
+
+        for question in &self.pool {
+            let information = Self::information(question, candidates);
+            if information > best.0 {
+                best = (information, Some(*question));
+            }
+        }
+
+        best.1.unwrap_or_else(|| {
+            let middle = candidates[candidates.len() / 2];
+            Predicate::Compare(Term::Number, Comparison::Less, middle)
+        })
+    }
+}
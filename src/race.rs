@@ -0,0 +1,272 @@
+//! Number guessing game where two players race to guess the same number.
+//!
+//! The players take turns guessing, and both see the answer to every guess. The
+//! first player to guess the number wins. Guessing the middle isn't the best
+//! here, as it leaves the other player a range that is as good as the one given.
+use std::cmp::Ordering;
+
+use super::{auto_guesser::Strategy, input, knowledge::KnowledgeState, NumberGuessingGame};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table() {
+        let mut strategy = RaceOptimal::default();
+        strategy.reset(1, 3);
+
+        assert_eq!(strategy.wins, vec![0.0, 1.0, 0.5, 2.0 / 3.0]);
+        // Guessing the middle of 3 numbers only wins a third of the time
+        assert_eq!(strategy.choices[3], 0);
+        assert_eq!(strategy.next_guess(), 1);
+    }
+}
+
+/// A number guessing game where two players race to guess the same number.
+pub struct RaceGame {
+    /// The underlying game.
+    game: NumberGuessingGame,
+    /// The player whose turn it is, 0 or 1.
+    player: usize,
+    /// The player who guessed the number.
+    winner: Option<usize>,
+    /// The last guess made and its result.
+    last_guess: Option<(i128, Ordering)>,
+}
+
+impl RaceGame {
+    /// Creates a race game, where the first player is player 0.
+    ///
+    /// An Err would be returned if min > max.
+    #[allow(clippy::result_unit_err)]
+    pub fn new(min: i128, max: i128) -> Result<Self, ()> {
+        let game = NumberGuessingGame::new(min, max)?;
+
+        Ok(Self {
+            game,
+            player: 0,
+            winner: None,
+            last_guess: None,
+        })
+    }
+
+    /// Makes a guess for the player whose turn it is.
+    ///
+    /// If the guess is incorrect, it becomes the other player's turn.
+    pub fn make_guess(&mut self, guess: i128) -> Ordering {
+        let result = self.game.make_guess(guess, |_, _, _| ());
+        self.last_guess = Some((guess, result));
+
+        match result {
+            Ordering::Equal => self.winner = Some(self.player),
+            _ => self.player = 1 - self.player,
+        }
+
+        result
+    }
+
+    /// Guess the number from standard input for the player whose turn it is.
+    ///
+    /// `true` would be returned if the guess is correct, `false` would be returned otherwise.
+    pub fn get_guess(&mut self) -> bool {
+        // Getting the guess from stdin
+        let guess = input::read_number(&format!("Player {}, guess a number: ", self.player + 1));
+
+        let status = self.make_guess(guess);
+        self.handle_guess(guess, status);
+
+        status == Ordering::Equal
+    }
+
+    /// Prints the result of a guess in a human readable form.
+    pub fn handle_guess(&self, guess: i128, result: Ordering) {
+        match result {
+            Ordering::Greater => println!("{guess} is too high."),
+            Ordering::Less => println!("{guess} is too low."),
+            Ordering::Equal => println!(
+                "{guess} is correct. Player {} wins after {} guesses.",
+                self.player + 1,
+                self.game.get_n_guesses()
+            ),
+        }
+    }
+
+    /// Plays the game with a strategy for each player, both seeing every answer.
+    ///
+    /// The player who guessed the number is returned.
+    pub fn solve(&mut self, players: [&mut dyn Strategy; 2]) -> usize {
+        let (min, max) = self.game.get_range();
+        let [first, second] = players;
+        first.reset(min, max);
+        second.reset(min, max);
+
+        loop {
+            let guess = match self.player {
+                0 => first.next_guess(),
+                _ => second.next_guess(),
+            };
+            let status = self.make_guess(guess);
+
+            first.update(guess, status);
+            second.update(guess, status);
+            if status == Ordering::Equal {
+                return self.player;
+            }
+        }
+    }
+
+    /// Resets the number currently stored, with player 0 going first.
+    pub fn reset_number(&mut self) {
+        self.game.reset_number();
+        self.player = 0;
+        self.winner = None;
+        self.last_guess = None;
+    }
+
+    /// Sets the number to guess, with player 0 going first.
+    ///
+    /// An Err would be returned if the number is not within the range. If this
+    /// happens the number would not change.
+    #[allow(clippy::result_unit_err)]
+    pub fn set_number(&mut self, number: i128) -> Result<(), ()> {
+        self.game.set_number(number)?;
+        self.player = 0;
+        self.winner = None;
+        self.last_guess = None;
+
+        Ok(())
+    }
+
+    /// Gets the player whose turn it is, 0 or 1.
+    pub fn player(&self) -> usize {
+        self.player
+    }
+
+    /// Gets the last guess made and its result, if a guess has been made.
+    pub fn last_guess(&self) -> Option<(i128, Ordering)> {
+        self.last_guess
+    }
+
+    /// Gets the player who guessed the number, if it has been guessed.
+    pub fn winner(&self) -> Option<usize> {
+        self.winner
+    }
+
+    /// Returns a reference to the NumberGuessingGame.
+    pub fn game(&self) -> &NumberGuessingGame {
+        &self.game
+    }
+}
+
+/// The most numbers [`RaceOptimal`] can be used with.
+pub const MAX_SIZE: u128 = 10_000;
+
+/// Minimax strategy for the race, assuming the other player also plays this way.
+///
+/// With n numbers left, a guess wins straight away 1 in n times, and otherwise
+/// the other player gets the numbers below or above it. The chance of winning
+/// with n numbers left is built for every n from the chances for fewer numbers,
+/// where the other player is assumed to make the best guess. No strategy wins
+/// more often against this one, but it may not take advantage of a weaker
+/// player. Building the table takes O(n²) time for a range of n numbers, so it
+/// can only be used on ranges of up to [`MAX_SIZE`] numbers and resetting it for
+/// a larger range panics.
+#[derive(Debug, Default)]
+pub struct RaceOptimal {
+    /// The chance of winning for the player to guess with n numbers left at `wins[n]`.
+    wins: Vec<f64>,
+    /// The amount of numbers below the best guess with n numbers left at `choices[n]`.
+    choices: Vec<usize>,
+    /// What the answers so far tell about the number.
+    knowledge: KnowledgeState,
+}
+
+impl RaceOptimal {
+    /// Gets the chance of winning for the player to guess, with the numbers left.
+    pub fn win_probability(&self) -> f64 {
+        self.wins[self.knowledge.size() as usize]
+    }
+
+    /// Builds the table of best guesses for up to `size` numbers.
+    fn build(&mut self, size: usize) {
+        self.wins = vec![0.0; size + 1];
+        self.choices = vec![0; size + 1];
+
+        for n in 1..=size {
+            let mut best = (f64::NEG_INFINITY, 0);
+
+            for below in 0..n {
+                let above = n - 1 - below;
+                // The other player guesses next with the numbers on one side
+                let win = (1.0
+                    + below as f64 * (1.0 - self.wins[below])
+                    + above as f64 * (1.0 - self.wins[above]))
+                    / n as f64;
+
+                // Prefer guesses closer to the middle
+                let middle = |x: usize| (2 * x + 1).abs_diff(n);
+                if win > best.0 + f64::EPSILON
+                    || ((win - best.0).abs() <= f64::EPSILON && middle(below) < middle(best.1))
+                {
+                    best = (win, below);
+                }
+            }
+
+            self.wins[n] = best.0;
+            self.choices[n] = best.1;
+        }
+    }
+}
+
+impl Strategy for RaceOptimal {
+    fn reset(&mut self, min: i128, max: i128) {
+        assert!(
+            max.abs_diff(min) < MAX_SIZE,
+            "There can be at most {MAX_SIZE} numbers in the range."
+        );
+        let size = max.abs_diff(min) as usize + 1;
+        if self.wins.len() != size + 1 {
+            self.build(size);
+        }
+
+        self.knowledge = KnowledgeState::new(min, max);
+    }
+
+    fn next_guess(&mut self) -> i128 {
+        let candidates = self.knowledge.candidates();
+        let below = self.choices[candidates.size() as usize];
+
+        candidates.nth(below as u128).unwrap_or_default()
+    }
+
+    fn update(&mut self, guess: i128, result: Ordering) {
+        // A contradicting answer is ignored
+        let _ = self.knowledge.observe(guess, result);
+    }
+}
+
+/// Plays `games` races on `min..=max` between two strategies, with `first` going first.
+///
+/// The number of games won by each strategy is returned.
+/// An Err would be returned if min > max. A strategy is reset for the range
+/// every game, so a [`RaceOptimal`] strategy panics if there are more than
+/// [`MAX_SIZE`] numbers in the range.
+#[allow(clippy::result_unit_err)]
+pub fn simulate(
+    min: i128,
+    max: i128,
+    games: u32,
+    first: &mut dyn Strategy,
+    second: &mut dyn Strategy,
+) -> Result<[u32; 2], ()> {
+    let mut game = RaceGame::new(min, max)?;
+    let mut wins = [0; 2];
+
+    for _ in 0..games {
+        game.reset_number();
+        wins[game.solve([&mut *first, &mut *second])] += 1;
+    }
+
+    Ok(wins)
+}
@@ -0,0 +1,312 @@
+//! Number guessing game where the maximum (and optionally the minimum) is not known.
+//!
+//! Strategies in this module also implement [`Strategy`] so they can be used with
+//! [`AutoNumberGuesser`](crate::AutoNumberGuesser). Bounds that are not known are
+//! given to the strategy as `i128::MIN` and `i128::MAX`.
+use std::cmp::Ordering;
+
+use rand::Rng;
+
+use super::{
+    auto_guesser::{self, Strategy},
+    input,
+    knowledge::KnowledgeState,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_offset() {
+        assert_eq!(offset(0), 0);
+        assert_eq!(offset(1), 1);
+        assert_eq!(offset(3), 7);
+        assert_eq!(offset(127), i128::MAX);
+        assert_eq!(offset(200), i128::MAX);
+    }
+}
+
+/// A number guessing game where only a lower bound, or no bound at all, is known.
+pub struct UnboundedGame {
+    /// Minimum the number can be, if it is known.
+    min: Option<i128>,
+    /// The number of guesses made.
+    guesses: u128,
+    /// The current number.
+    number: i128,
+}
+
+impl UnboundedGame {
+    /// Creates an unbounded number guessing game.
+    ///
+    /// The number is set to `min`, or 0 if there isn't a minimum.
+    pub fn new(min: Option<i128>) -> Self {
+        Self {
+            min,
+            guesses: 0,
+            number: min.unwrap_or(0),
+        }
+    }
+
+    /// Makes a guess of the number.
+    pub fn make_guess<T>(&mut self, guess: i128, guess_handler: T) -> Ordering
+    where
+        T: FnOnce(Ordering, u128, i128),
+    {
+        // Increasing the amount of guesses
+        self.guesses += 1;
+
+        let result = guess.cmp(&self.number);
+        guess_handler(result, self.guesses, self.number);
+
+        result
+    }
+
+    /// Guess the number from standard input.
+    ///
+    /// `true` would be returned if the guess is correct, `false` would be returned otherwise.
+    pub fn get_guess(&mut self) -> bool {
+        // Getting the guess from stdin
+        let guess = input::read_number("Guess a number: ");
+
+        let status = self.make_guess(guess, Self::handle_guess);
+
+        status == Ordering::Equal
+    }
+
+    /// Prints the result of make_guess() in a human readable form.
+    fn handle_guess(result: Ordering, guesses: u128, number: i128) {
+        match result {
+            Ordering::Greater => println!("Your guess is too high."),
+            Ordering::Less => println!("Your guess is too low."),
+            Ordering::Equal => println!("Congratulations!! Your guess is correct. The number is {number}. You took {guesses} guesses."),
+        }
+    }
+
+    /// Resets the number to a random number at most `magnitude` away from the minimum.
+    ///
+    /// If there isn't a minimum the number is at most `magnitude` away from 0 in
+    /// either direction.
+    pub fn reset_number(&mut self, magnitude: u128) {
+        let mut rng = rand::thread_rng();
+        let magnitude = magnitude.min(i128::MAX as u128) as i128;
+
+        self.number = match self.min {
+            Some(min) => rng.gen_range(min..=min.saturating_add(magnitude)),
+            None => rng.gen_range(-magnitude..=magnitude),
+        };
+        self.guesses = 0;
+    }
+
+    /// Sets the number to guess.
+    ///
+    /// An Err would be returned if the number is less than the minimum. If this
+    /// happens the number would not change.
+    #[allow(clippy::result_unit_err)]
+    pub fn set_number(&mut self, number: i128) -> Result<(), ()> {
+        if self.min.is_some_and(|min| number < min) {
+            return Err(());
+        }
+
+        self.number = number;
+        self.guesses = 0;
+
+        Ok(())
+    }
+
+    /// Gets the minimum the number can be, if it is known.
+    pub fn min(&self) -> Option<i128> {
+        self.min
+    }
+
+    /// Gets the number of guesses made.
+    pub fn get_n_guesses(&self) -> u128 {
+        self.guesses
+    }
+
+    /// Guesses the current number using a strategy.
+    ///
+    /// The number of guesses is reset before guessing and the number of guesses
+    /// made is returned.
+    pub fn solve(&mut self, strategy: &mut dyn Strategy) -> u128 {
+        self.guesses = 0;
+        let min = self.min.unwrap_or(i128::MIN);
+
+        auto_guesser::play(strategy, min, i128::MAX, |guess| {
+            self.make_guess(guess, |_, _, _| ())
+        });
+
+        self.guesses
+    }
+}
+
+/// Phase of an unbounded search.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Phase {
+    /// Guessing the origin to find the direction of the number.
+    Origin,
+    /// Guessing `origin ± (2^k - 1)`, increasing k.
+    Gallop(u32),
+    /// Binary search for the smallest k where the number isn't beyond `origin ± (2^k - 1)`.
+    Exponent(u32, u32),
+    /// Bisection of the remaining range.
+    Bisect,
+}
+
+/// The known state of an unbounded search.
+#[derive(Debug, Clone)]
+struct Search {
+    /// What the answers so far tell about the number.
+    knowledge: KnowledgeState,
+    /// Where the search starts from.
+    origin: i128,
+    /// `true` if the number is below the origin.
+    downwards: bool,
+    /// Current phase of the search.
+    phase: Phase,
+}
+
+impl Search {
+    /// Creates a search for a number in `min..=max`.
+    fn new(min: i128, max: i128) -> Self {
+        let origin = if min == i128::MIN {
+            0.clamp(min, max)
+        } else {
+            min
+        };
+
+        Self {
+            knowledge: KnowledgeState::new(min, max),
+            origin,
+            downwards: false,
+            phase: Phase::Origin,
+        }
+    }
+
+    /// Gets the guess `origin ± (2^k - 1)` inside the current range.
+    fn position(&self, k: u32) -> i128 {
+        let position = match self.downwards {
+            true => self.origin.saturating_sub(offset(k)),
+            false => self.origin.saturating_add(offset(k)),
+        };
+
+        match self.knowledge.bounds() {
+            Some((min, max)) => position.clamp(min, max),
+            None => position,
+        }
+    }
+
+    /// Gets the middle of the current range.
+    fn middle(&self) -> i128 {
+        self.knowledge.candidates().median().unwrap_or(self.origin)
+    }
+
+    /// Updates the current range and returns `true` if the number is further from the origin.
+    fn update(&mut self, guess: i128, result: Ordering) -> bool {
+        // A contradicting answer is ignored
+        let _ = self.knowledge.observe(guess, result);
+
+        if self.phase == Phase::Origin {
+            self.downwards = result == Ordering::Greater;
+        }
+
+        match self.downwards {
+            true => result == Ordering::Greater,
+            false => result == Ordering::Less,
+        }
+    }
+}
+
+impl Default for Search {
+    fn default() -> Self {
+        Self::new(i128::MIN, i128::MAX)
+    }
+}
+
+/// Gets `2^k - 1`, saturating at `i128::MAX`.
+fn offset(k: u32) -> i128 {
+    match k {
+        0..=126 => (1 << k) - 1,
+        _ => i128::MAX,
+    }
+}
+
+/// Galloping (exponential) search.
+///
+/// The guesses move away from the origin by `2^k - 1` until the number is passed,
+/// then the remaining range is bisected. This takes about `2 log2(n)` guesses
+/// where n is the distance of the number from the origin.
+#[derive(Debug, Default)]
+pub struct Galloping {
+    search: Search,
+}
+
+impl Strategy for Galloping {
+    fn reset(&mut self, min: i128, max: i128) {
+        self.search = Search::new(min, max);
+    }
+
+    fn next_guess(&mut self) -> i128 {
+        match self.search.phase {
+            Phase::Origin => self.search.origin,
+            Phase::Gallop(k) => self.search.position(k),
+            _ => self.search.middle(),
+        }
+    }
+
+    fn update(&mut self, guess: i128, result: Ordering) {
+        let beyond = self.search.update(guess, result);
+
+        self.search.phase = match (self.search.phase, beyond) {
+            (Phase::Origin, true) => Phase::Gallop(1),
+            (Phase::Gallop(k), true) => Phase::Gallop(k + 1),
+            _ => Phase::Bisect,
+        };
+    }
+}
+
+/// Bentley–Yao style search.
+///
+/// Instead of increasing k one at a time like [`Galloping`], k is doubled until
+/// the number is passed and then found by a binary search, before the remaining
+/// range is bisected. This takes about `log2(n) + 2 log2(log2(n))` guesses.
+#[derive(Debug, Default)]
+pub struct BentleyYao {
+    search: Search,
+}
+
+impl Strategy for BentleyYao {
+    fn reset(&mut self, min: i128, max: i128) {
+        self.search = Search::new(min, max);
+    }
+
+    fn next_guess(&mut self) -> i128 {
+        match self.search.phase {
+            Phase::Origin => self.search.origin,
+            Phase::Gallop(k) => self.search.position(k),
+            Phase::Exponent(low, high) => self.search.position(low + (high - low) / 2),
+            Phase::Bisect => self.search.middle(),
+        }
+    }
+
+    fn update(&mut self, guess: i128, result: Ordering) {
+        let beyond = self.search.update(guess, result);
+
+        self.search.phase = match (self.search.phase, beyond) {
+            (Phase::Origin, true) => Phase::Gallop(1),
+            (Phase::Gallop(k), true) => Phase::Gallop(k * 2),
+            // k is between the previous and current gallop
+            (Phase::Gallop(k), false) if k > 2 => Phase::Exponent(k / 2 + 1, k - 1),
+            (Phase::Exponent(low, high), _) => {
+                let k = low + (high - low) / 2;
+                match beyond {
+                    true if k < high => Phase::Exponent(k + 1, high),
+                    false if k > low => Phase::Exponent(low, k - 1),
+                    _ => Phase::Bisect,
+                }
+            }
+            _ => Phase::Bisect,
+        };
+    }
+}
@@ -0,0 +1,120 @@
+use std::cmp::Ordering;
+
+use guess_my_number_rs::{
+    analysis::{GameReport, SecretReport},
+    auto_guesser::{self, Bisection},
+    NumberGuessingGame,
+};
+
+#[test]
+fn report() {
+    let report = SecretReport::new(1, 10, Box::<Bisection>::default()).unwrap();
+
+    // Every secret is played once
+    let secrets: Vec<i128> = report.results().iter().map(|x| x.secret()).collect();
+    assert_eq!(secrets, (1..=10).collect::<Vec<i128>>());
+
+    // Every path ends with the secret
+    for result in report.results() {
+        assert_eq!(result.path().last(), Some(&result.secret()));
+        assert_eq!(result.guesses(), result.path().len() as u128);
+    }
+
+    // The first guess is always the middle
+    assert_eq!(report.results()[4].path(), &[5]);
+    assert_eq!(report.min_guesses(), 1);
+
+    // Worst case secrets all need the most guesses
+    let worst = report.worst_case();
+    assert!(!worst.is_empty());
+    assert!(worst.iter().all(|x| x.guesses() == report.max_guesses()));
+
+    assert!(
+        SecretReport::new(10, 1, Box::<Bisection>::default()).is_err(),
+        "Maximum should be greater than minimum"
+    );
+    assert!(
+        SecretReport::new(0, i128::MAX, Box::<Bisection>::default()).is_err(),
+        "The range is too large to play every secret"
+    );
+}
+
+#[test]
+fn csv() {
+    let report = SecretReport::new(1, 3, Box::<Bisection>::default()).unwrap();
+    let mut output = Vec::new();
+    report.write_csv(&mut output).unwrap();
+
+    let output = String::from_utf8(output).unwrap();
+    assert_eq!(output, "Secret,Guesses,Path\n1,2,2 1\n2,1,2\n3,2,2 3\n");
+}
+
+#[test]
+fn heatmap() {
+    let report = SecretReport::new(1, 100, Box::<Bisection>::default()).unwrap();
+
+    // Width is capped at the range size
+    let heatmap = report.heatmap(200);
+    let line = heatmap.lines().nth(1).unwrap();
+    assert_eq!(line.chars().count(), 102);
+
+    // Width is respected
+    let heatmap = report.heatmap(20);
+    let line = heatmap.lines().nth(1).unwrap();
+    assert_eq!(line.chars().count(), 22);
+    assert!(heatmap.lines().next().unwrap().starts_with('1'));
+    assert!(heatmap.lines().next().unwrap().ends_with("100"));
+}
+
+#[test]
+fn game_report() {
+    let mut game = NumberGuessingGame::new(1, 100).unwrap();
+    game.set_number(42).unwrap();
+    game.make_guess(69, |_, _, _| ());
+    assert!(
+        game.history().is_empty(),
+        "The history should be off by default"
+    );
+
+    game.set_record_history(true);
+    game.set_number(42).unwrap();
+    for guess in [50, 60, 20, 42] {
+        game.make_guess(guess, |_, _, _| ());
+    }
+
+    let report = GameReport::new(&game);
+    let reviews = report.reviews();
+    assert_eq!(reviews.len(), 4);
+
+    // The range before every guess
+    assert_eq!((reviews[0].min, reviews[0].max), (1, 100));
+    assert_eq!((reviews[1].min, reviews[1].max), (1, 49));
+    assert_eq!((reviews[3].min, reviews[3].max), (21, 49));
+
+    // The guess of 60 was wasted
+    assert!(!reviews[1].is_inside());
+    assert_eq!(reviews[1].bits, 0.0);
+    assert_eq!(report.wasted(), 1);
+    assert!((report.total_bits() - 100.0_f64.log2()).abs() < 1e-9);
+
+    // How the optimal strategy would have played
+    assert_eq!(reviews[0].optimal, 50);
+    assert_eq!(reviews[0].worst_case, 7);
+    assert_eq!(reviews[3].optimal, 35);
+    assert_eq!(reviews[3].result, Ordering::Equal);
+    let mut strategy = Bisection::default();
+    let guesses = auto_guesser::play(&mut strategy, 1, 100, |x| x.cmp(&42));
+    assert_eq!(reviews[0].optimal_guesses, Some(guesses));
+
+    assert!(report.report().contains("1 outside the range"));
+}
+
+#[test]
+fn game_report_unfinished() {
+    let history = [(50, Ordering::Greater), (10, Ordering::Less)];
+    let report = GameReport::from_history(1, 100, &history);
+
+    assert_eq!(report.reviews().len(), 2);
+    assert!(report.reviews().iter().all(|x| x.optimal_guesses.is_none()));
+    assert_eq!(report.wasted(), 0);
+}
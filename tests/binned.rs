@@ -0,0 +1,145 @@
+use guess_my_number_rs::binned::{self, Binning, Bins};
+
+#[test]
+fn width() {
+    let samples: Vec<u128> = vec![1, 2, 9, 10, 11, 25];
+    let bins = Bins::new(Binning::Width(10.0));
+    assert_eq!(bins.edges(&samples).unwrap(), vec![0.0, 10.0, 20.0, 30.0]);
+
+    let histogram = bins.histogram(&samples).unwrap();
+    let counts: Vec<(String, u128)> = histogram
+        .data()
+        .iter()
+        .map(|(bin, count)| (bin.to_string(), *count))
+        .collect();
+    assert_eq!(
+        counts,
+        vec![
+            ("0..=9".to_string(), 3),
+            ("10..=19".to_string(), 2),
+            ("20..=29".to_string(), 1)
+        ]
+    );
+
+    // Float samples
+    let samples = vec![0.1, 0.4, 0.45, 0.9];
+    let histogram = Bins::new(Binning::Width(0.5)).histogram(&samples).unwrap();
+    let counts: Vec<u128> = histogram.data().values().copied().collect();
+    assert_eq!(counts, vec![3, 1]);
+    let first = histogram.data().keys().next().unwrap();
+    assert_eq!((first.start(), first.end()), (0.0, 0.5));
+    assert_eq!(first.to_string(), "0.00..0.50");
+
+    // Invalid widths
+    assert!(
+        Bins::new(Binning::Width(0.0)).edges(&samples).is_err(),
+        "Width should be positive"
+    );
+    assert!(
+        Bins::new(Binning::Width(1e-9)).edges(&samples).is_err(),
+        "There should be at most MAX_BINS bins"
+    );
+}
+
+#[test]
+fn count() {
+    let samples = vec![0.0, 1.0, 2.0, 3.0, 4.0];
+    let bins = Bins::new(Binning::Count(4));
+    assert_eq!(bins.edges(&samples).unwrap(), vec![0.0, 1.0, 2.0, 3.0, 4.0]);
+
+    // The largest sample is in the last bin
+    let histogram = bins.histogram(&samples).unwrap();
+    let counts: Vec<u128> = histogram.data().values().copied().collect();
+    assert_eq!(counts, vec![1, 1, 1, 2]);
+
+    // Integer bins hold whole numbers
+    let samples: Vec<i32> = (1..=10).collect();
+    let edges = Bins::new(Binning::Count(3)).edges(&samples).unwrap();
+    assert_eq!(edges, vec![1.0, 5.0, 9.0, 13.0]);
+
+    assert!(
+        Bins::new(Binning::Count(binned::MAX_BINS + 1))
+            .edges(&samples)
+            .is_err(),
+        "There should be at most MAX_BINS bins"
+    );
+
+    // Empty samples
+    assert!(
+        Bins::new(Binning::Count(3)).edges::<i32>(&[]).is_err(),
+        "There should be samples"
+    );
+}
+
+#[test]
+fn log() {
+    let samples: Vec<u64> = vec![1, 5, 10, 99, 100, 1000];
+    let histogram = Bins::new(Binning::Log(10.0)).histogram(&samples).unwrap();
+    let counts: Vec<(String, u128)> = histogram
+        .data()
+        .iter()
+        .map(|(bin, count)| (bin.to_string(), *count))
+        .collect();
+    assert_eq!(
+        counts,
+        vec![
+            ("1..=9".to_string(), 2),
+            ("10..=99".to_string(), 2),
+            ("100..=999".to_string(), 1),
+            ("1000..=9999".to_string(), 1)
+        ]
+    );
+
+    // Samples should be positive
+    assert!(
+        Bins::new(Binning::Log(10.0)).edges(&[0, 1]).is_err(),
+        "Samples should be positive"
+    );
+    assert!(
+        Bins::new(Binning::Log(1.000001)).edges(&[1, 1000]).is_err(),
+        "There should be at most MAX_BINS bins"
+    );
+}
+
+#[test]
+fn merge() {
+    // Bins with the same index but different edges stay apart
+    let mut histogram = Bins::new(Binning::Width(10.0)).histogram(&[1, 2]).unwrap();
+    let other = Bins::new(Binning::Width(5.0)).histogram(&[1, 7]).unwrap();
+    histogram.merge(&other);
+
+    let counts: Vec<(String, u128)> = histogram
+        .data()
+        .iter()
+        .map(|(bin, count)| (bin.to_string(), *count))
+        .collect();
+    assert_eq!(
+        counts,
+        vec![
+            ("0..=4".to_string(), 1),
+            ("0..=9".to_string(), 2),
+            ("5..=9".to_string(), 1)
+        ]
+    );
+}
+
+#[test]
+fn rules() {
+    let samples: Vec<u32> = (0..64).collect();
+
+    // log2(64) + 1 bins
+    let edges = Bins::new(Binning::Sturges).edges(&samples).unwrap();
+    assert_eq!(edges.len(), 8);
+
+    // 2 * 31.5 / 4, rounded up to whole numbers
+    let edges = Bins::new(Binning::FreedmanDiaconis)
+        .edges(&samples)
+        .unwrap();
+    assert_eq!(edges[1] - edges[0], 16.0);
+
+    // Equal samples fall back to Sturges' rule
+    let histogram = Bins::new(Binning::FreedmanDiaconis)
+        .histogram(&[3.0; 10])
+        .unwrap();
+    assert_eq!(histogram.data().values().sum::<u128>(), 10);
+}
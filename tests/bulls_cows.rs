@@ -0,0 +1,93 @@
+use guess_my_number_rs::{
+    auto_guesser::KnuthMinimax,
+    bulls_cows::{self, BullsCowsGame, Score},
+};
+
+#[test]
+fn constructor() {
+    let game = BullsCowsGame::new(4, true).unwrap();
+    assert_eq!(game.digits(), 4);
+    assert!(game.distinct());
+
+    assert!(
+        BullsCowsGame::new(0, false).is_err(),
+        "There should be at least 1 digit"
+    );
+    assert!(
+        BullsCowsGame::new(11, true).is_err(),
+        "There should be at most 10 digits"
+    );
+}
+
+#[test]
+fn score() {
+    assert_eq!(Score::of(&[1, 2, 3, 4], &[1, 2, 3, 4]), Score::new(4, 0));
+    assert_eq!(Score::of(&[1, 2, 3, 4], &[4, 3, 2, 1]), Score::new(0, 4));
+    assert_eq!(Score::of(&[1, 2, 3, 4], &[1, 3, 5, 6]), Score::new(1, 1));
+    // Repeated digits are only counted once
+    assert_eq!(Score::of(&[1, 1, 2, 2], &[1, 2, 1, 1]), Score::new(1, 2));
+    assert_eq!(Score::of(&[5, 5, 5, 5], &[5, 0, 0, 0]), Score::new(1, 0));
+}
+
+#[test]
+fn codes() {
+    assert_eq!(bulls_cows::codes(4, true).len(), 5040);
+    assert_eq!(bulls_cows::codes(3, false).len(), 1000);
+    assert_eq!(bulls_cows::codes(2, true)[..3], [[0, 1], [0, 2], [0, 3]]);
+    assert_eq!(bulls_cows::code_to_string(&[0, 4, 2]), "042");
+}
+
+#[test]
+fn make_guess() {
+    let mut game = BullsCowsGame::new(4, true).unwrap();
+    game.set_number(&[0, 4, 2, 7]).unwrap();
+
+    assert_eq!(game.make_guess(&[0, 2, 4, 9]), Ok(Score::new(1, 2)));
+    let score = game.make_guess(&[0, 4, 2, 7]).unwrap();
+    assert!(game.is_correct(score));
+    assert_eq!(game.get_n_guesses(), 2);
+
+    assert!(
+        game.make_guess(&[1, 1, 2, 3]).is_err(),
+        "Guess should have distinct digits"
+    );
+    assert!(
+        game.make_guess(&[1, 2, 3]).is_err(),
+        "Guess should have 4 digits"
+    );
+    assert!(
+        game.set_number(&[1, 2, 3, 10]).is_err(),
+        "Code should only have digits"
+    );
+}
+
+#[test]
+fn knuth_minimax() {
+    let mut game = BullsCowsGame::new(3, true).unwrap();
+    let mut strategy = KnuthMinimax::new(3, true).unwrap();
+
+    for code in bulls_cows::codes(3, true).iter().step_by(37) {
+        game.set_number(code).unwrap();
+        // Every 3 digit code is found within 7 guesses
+        assert!(game.solve(&mut strategy) <= 7);
+    }
+
+    // Repeated digits
+    let mut game = BullsCowsGame::new(2, false).unwrap();
+    let mut strategy = KnuthMinimax::new(2, false).unwrap();
+    for code in bulls_cows::codes(2, false) {
+        game.set_number(&code).unwrap();
+        assert!(game.solve(&mut strategy) <= 7);
+    }
+
+    // Inconsistent scores leave no codes
+    strategy.reset();
+    strategy.update(&[1, 2], Score::new(2, 0));
+    strategy.update(&[1, 2], Score::new(0, 0));
+    assert_eq!(strategy.next_guess(), None);
+
+    assert!(
+        KnuthMinimax::new(5, false).is_err(),
+        "There should be at most 4 digits"
+    );
+}
@@ -0,0 +1,120 @@
+use guess_my_number_rs::{
+    auto_guesser::CircularBisection,
+    circular::{Arc, CircularGame, Turn},
+};
+
+#[test]
+fn constructor() {
+    let game = CircularGame::new(0, 359).unwrap();
+    assert_eq!(game.get_range(), (0, 359));
+    assert_eq!(game.size(), 360);
+
+    assert!(
+        CircularGame::new(0, -69).is_err(),
+        "Maximum should be greater than minimum"
+    );
+    assert!(
+        CircularGame::new(i128::MIN, i128::MAX).is_err(),
+        "The ring has more numbers than fit in a u128"
+    );
+}
+
+#[test]
+fn turn() {
+    // Clock hours
+    assert_eq!(CircularGame::turn(1, 12, 11, 2), Turn::Clockwise);
+    assert_eq!(CircularGame::turn(1, 12, 2, 11), Turn::CounterClockwise);
+    assert_eq!(CircularGame::turn(1, 12, 3, 3), Turn::Correct);
+    // Opposite is clockwise
+    assert_eq!(CircularGame::turn(1, 12, 12, 6), Turn::Clockwise);
+    // Guesses are wrapped onto the ring
+    assert_eq!(CircularGame::turn(0, 359, 360, 0), Turn::Correct);
+    assert_eq!(CircularGame::turn(0, 359, -10, 340), Turn::CounterClockwise);
+}
+
+#[test]
+fn make_guess() {
+    let mut game = CircularGame::new(0, 359).unwrap();
+    game.set_number(350).unwrap();
+
+    assert_eq!(game.make_guess(10, |_, _, _| ()), Turn::CounterClockwise);
+    assert!(game.remaining().contains(0, 359, 350));
+    assert_eq!(game.remaining().len(), 179);
+
+    assert_eq!(game.make_guess(300, |_, _, _| ()), Turn::Clockwise);
+    assert_eq!(game.remaining(), Arc::new(0, 359, 301, 69));
+    assert_eq!(game.get_n_guesses(), 2);
+
+    assert!(
+        game.set_number(360).is_err(),
+        "Number should be within the range"
+    );
+}
+
+#[test]
+fn answers() {
+    // The number is always in the remaining arc, whatever is guessed
+    let mut game = CircularGame::new(1, 12).unwrap();
+    for number in 1..=12 {
+        game.set_number(number).unwrap();
+        for guess in [7, 1, 12, 3, 4, 9] {
+            game.make_guess(guess, |_, _, _| ());
+            assert!(game.remaining().contains(1, 12, number));
+        }
+    }
+}
+
+#[test]
+fn circular_bisection() {
+    for (min, max) in [(0, 0), (1, 12), (0, 359), (-50, 50)] {
+        let mut game = CircularGame::new(min, max).unwrap();
+        let size = game.size() as f32;
+
+        for number in min..=max {
+            game.set_number(number).unwrap();
+            let guesses = game.solve(&mut CircularBisection::default());
+            assert!(guesses <= size.log2() as u128 + 2);
+        }
+    }
+}
+
+#[test]
+fn render() {
+    let mut game = CircularGame::new(0, 359).unwrap();
+    game.set_number(90).unwrap();
+    game.make_guess(0, |_, _, _| ());
+
+    let render = game.render(4, Some(0));
+    assert!(render.starts_with(&format!("{}0\n", " ".repeat(8))));
+    // The guess is at the top
+    assert!(render.lines().nth(1).unwrap().contains('o'));
+    assert!(render.contains('#'));
+    assert!(render.contains('.'));
+    assert!(render.ends_with("The number is in the 180 numbers clockwise from 1.\n"));
+
+    // Huge rings don't go through every number
+    let mut game = CircularGame::new(i128::MIN, i128::MAX - 1).unwrap();
+    game.set_number(0).unwrap();
+    game.make_guess(i128::MAX - 1, |_, _, _| ());
+    let render = game.render(4, None);
+    assert!(render.contains('#'));
+    assert!(render.contains('.'));
+}
+
+#[test]
+fn huge_ring() {
+    let (min, max) = (i128::MIN, i128::MAX - 1);
+    let mut game = CircularGame::new(min, max).unwrap();
+    assert_eq!(game.size(), u128::MAX);
+    assert_eq!(CircularGame::turn(min, max, max, min), Turn::Clockwise);
+    assert_eq!(
+        CircularGame::turn(min, max, min, max),
+        Turn::CounterClockwise
+    );
+
+    for number in [min, -1, 0, max] {
+        game.set_number(number).unwrap();
+        let guesses = game.solve(&mut CircularBisection::default());
+        assert!(guesses <= 130);
+    }
+}
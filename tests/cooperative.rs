@@ -0,0 +1,61 @@
+use guess_my_number_rs::cooperative::{CoopGame, Guesser};
+use std::cmp::Ordering;
+
+#[test]
+fn constructor() {
+    let game = CoopGame::new(1, 100).unwrap();
+    assert_eq!(game.get_range(), (1, 100));
+    assert!(game.moves().is_empty());
+    assert!(!game.is_over());
+
+    assert!(
+        CoopGame::new(0, -69).is_err(),
+        "Maximum should be greater than minimum"
+    );
+}
+
+#[test]
+fn make_guess() {
+    let mut game = CoopGame::new(1, 100).unwrap();
+    game.set_number(42).unwrap();
+
+    let guess = game.make_guess(Guesser::Human, 90);
+    assert_eq!(guess.result, Ordering::Greater);
+    assert_eq!((guess.before, guess.after), (100, 89));
+    assert_eq!(game.get_range(), (1, 89));
+
+    // Guesses outside the interval gain nothing
+    let guess = game.make_guess(Guesser::Human, 95);
+    assert_eq!(guess.bits(), 0.0);
+
+    // The AI guesses the middle of the interval left
+    let guess = game.ai_guess();
+    assert_eq!(guess.guess, 45);
+    assert_eq!(guess.guesser, Guesser::AI);
+    assert_eq!(game.get_range(), (1, 44));
+    assert!(game.explain().contains("from 1 to 44"));
+}
+
+#[test]
+fn bits() {
+    let mut game = CoopGame::new(1, 64).unwrap();
+
+    for number in 1..=64 {
+        game.set_number(number).unwrap();
+
+        // Taking turns until the number is found
+        let mut guess = 1;
+        while !game.is_over() {
+            game.make_guess(Guesser::Human, guess);
+            guess += 7;
+            if !game.is_over() {
+                game.ai_guess();
+            }
+        }
+
+        // Every guess together gains all of the information
+        let bits: f64 = game.moves().iter().map(|x| x.bits()).sum();
+        assert!((bits - 6.0).abs() < 1e-9);
+        assert!(game.summary().contains("Bits per Guess"));
+    }
+}
@@ -0,0 +1,156 @@
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, VecDeque},
+};
+
+use guess_my_number_rs::delayed::{self, DelayedGame, DelayedStrategy, Pipelined};
+
+#[test]
+fn constructor() {
+    let game = DelayedGame::new(0, 69, 2).unwrap();
+    assert_eq!(game.game().get_range(), (0, 69));
+    assert_eq!(game.delay(), 2);
+
+    assert!(
+        DelayedGame::new(0, -69, 2).is_err(),
+        "Maximum should be greater than minimum"
+    );
+
+    // A long delay finds a number a turn
+    let turns = delayed::MAX_TURNS as i128;
+    assert!(DelayedGame::new(1, turns, u128::MAX).is_ok());
+    assert!(
+        DelayedGame::new(1, turns + 1, u128::MAX).is_err(),
+        "There should be at most MAX_TURNS turns"
+    );
+}
+
+#[test]
+fn make_guess() {
+    let mut game = DelayedGame::new(0, 10, 2).unwrap();
+    game.set_number(5).unwrap();
+
+    // Answers arrive 2 turns later
+    assert_eq!(game.make_guess(Some(1)), None);
+    assert_eq!(game.make_guess(Some(9)), None);
+    assert_eq!(game.make_guess(Some(5)), Some((1, Ordering::Less)));
+    assert_eq!(game.make_guess(None), Some((9, Ordering::Greater)));
+    assert_eq!(game.make_guess(None), Some((5, Ordering::Equal)));
+    assert_eq!(game.make_guess(None), None);
+
+    assert_eq!(game.get_n_guesses(), 3);
+    assert_eq!(game.get_n_turns(), 6);
+
+    // Without a delay answers arrive at once
+    let mut game = DelayedGame::new(0, 10, 0).unwrap();
+    game.set_number(5).unwrap();
+    assert_eq!(game.make_guess(Some(1)), Some((1, Ordering::Less)));
+}
+
+#[test]
+fn pipelined() {
+    for delay in 0..4 {
+        for max in 1..=60 {
+            let mut game = DelayedGame::new(1, max, delay).unwrap();
+
+            // Turns needed in the worst case
+            let mut turns = 1;
+            while delayed::capacity(turns, delay).unwrap() < max as u128 {
+                turns += 1;
+            }
+
+            for number in 1..=max {
+                game.set_number(number).unwrap();
+                let latency = game.solve(&mut Pipelined::default());
+                assert!(latency <= turns + delay);
+                assert_eq!(game.game().knowledge().known(), Some(number));
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn pipelined_too_large() {
+    let mut strategy = Pipelined::default();
+    strategy.reset(0, delayed::MAX_TURNS as i128, u128::MAX);
+}
+
+#[test]
+fn latency() {
+    // Pipelining takes fewer turns than waiting for every answer
+    let mut game = DelayedGame::new(1, 1000, 3).unwrap();
+    for _ in 0..100 {
+        game.reset_number();
+        let latency = game.solve(&mut Pipelined::default());
+        assert!(latency < 10 * 4);
+    }
+}
+
+/// Answers that arrived and guesses waiting for their answer, with the turns left.
+type State = (i128, i128, VecDeque<Option<i128>>, u128);
+
+/// Returns `true` if the number in `min..=max` can always be found within
+/// `turns` turns, trying every guess and every wait.
+///
+/// The guesses waiting for their answer are in `pending`, which always has
+/// `delay` entries where None is a turn without a guess.
+fn can_find(
+    (min, max, pending, turns): State,
+    range: (i128, i128),
+    cache: &mut HashMap<State, bool>,
+) -> bool {
+    if min > max {
+        return true;
+    }
+    if turns == 0 {
+        return false;
+    }
+    let state = (min, max, pending.clone(), turns);
+    if let Some(found) = cache.get(&state) {
+        return *found;
+    }
+
+    let found = std::iter::once(None)
+        .chain((range.0..=range.1).map(Some))
+        .any(|guess| {
+            let mut pending = pending.clone();
+            pending.push_back(guess);
+            let next = |min, max, pending: &VecDeque<_>| (min, max, pending.clone(), turns - 1);
+
+            match pending.pop_front().unwrap() {
+                None => can_find(next(min, max, &pending), range, cache),
+                // The number being the guess ends the game
+                Some(guess) => {
+                    can_find(next(min, max.min(guess - 1), &pending), range, cache)
+                        && can_find(next(min.max(guess + 1), max, &pending), range, cache)
+                }
+            }
+        });
+
+    cache.insert(state, found);
+    found
+}
+
+#[test]
+fn pipelined_optimal() {
+    for delay in 0..3 {
+        for max in 1..=10 {
+            let mut game = DelayedGame::new(1, max, delay).unwrap();
+            let worst = (1..=max)
+                .map(|number| {
+                    game.set_number(number).unwrap();
+                    game.solve(&mut Pipelined::default())
+                })
+                .max()
+                .unwrap();
+
+            // No strategy finds every number in fewer turns
+            let mut cache = HashMap::new();
+            let pending: VecDeque<_> = vec![None; delay as usize].into();
+            let state = |turns| (1, max, pending.clone(), turns);
+            assert!(can_find(state(worst), (1, max), &mut cache));
+            assert!(!can_find(state(worst - 1), (1, max), &mut cache));
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use std::cmp::Ordering;
+
+use guess_my_number_rs::{
+    auto_guesser::Bisection,
+    drifting::{DriftTracking, DriftingGame},
+};
+
+#[test]
+fn constructor() {
+    let game = DriftingGame::new(0, 69, 2).unwrap();
+    assert_eq!(game.get_range(), (0, 69));
+    assert_eq!(game.drift(), 2);
+
+    assert!(
+        DriftingGame::new(0, -69, 2).is_err(),
+        "Maximum should be greater than minimum"
+    );
+    assert!(
+        DriftingGame::new(0, 69, -1).is_err(),
+        "Drift should not be negative"
+    );
+}
+
+#[test]
+fn drift() {
+    let mut game = DriftingGame::new(0, 100, 3).unwrap();
+
+    for _ in 0..100 {
+        game.set_number(50).unwrap();
+        game.make_guess(0, |_, _, _| ());
+
+        // The number moved at most the drift
+        let mut number = 0;
+        game.make_guess(0, |_, _, x| number = x);
+        assert!((47..=53).contains(&number));
+    }
+
+    // The number stays within the range
+    game.set_number(100).unwrap();
+    for _ in 0..100 {
+        assert_ne!(game.make_guess(101, |_, _, _| ()), Ordering::Less);
+    }
+
+    // Without a drift the number doesn't move
+    let mut game = DriftingGame::new(0, 100, 0).unwrap();
+    game.set_number(69).unwrap();
+    for _ in 0..10 {
+        game.make_guess(0, |_, _, _| ());
+    }
+    assert_eq!(game.make_guess(69, |_, _, _| ()), Ordering::Equal);
+}
+
+#[test]
+fn drift_tracking() {
+    // Without a drift it is bisection
+    let mut game = DriftingGame::new(1, 1000, 0).unwrap();
+    for number in 1..=1000 {
+        game.set_number(number).unwrap();
+        assert!(game.solve(&mut DriftTracking::new(0), 1000).unwrap() <= 10);
+    }
+
+    // Always finds a moving number eventually
+    let mut game = DriftingGame::new(1, 1000, 5).unwrap();
+    for _ in 0..100 {
+        game.reset_number();
+        assert!(game.solve(&mut DriftTracking::new(5), 100_000).is_some());
+    }
+}
+
+#[test]
+fn limit() {
+    // Bisection on a single number finds it at once
+    let mut game = DriftingGame::new(1, 1, 5).unwrap();
+    assert_eq!(game.solve(&mut Bisection::default(), 1), Some(1));
+
+    // Gives up after the limit
+    let mut game = DriftingGame::new(1, 1000, 0).unwrap();
+    game.set_number(1).unwrap();
+    assert_eq!(game.solve(&mut DriftTracking::new(0), 3), None);
+    assert_eq!(game.get_n_guesses(), 3);
+}
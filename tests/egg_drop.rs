@@ -0,0 +1,109 @@
+use std::cmp::Ordering;
+
+use guess_my_number_rs::{
+    auto_guesser::{Bisection, Strategy},
+    egg_drop::{self, Costs, EggDropGame, Objective, OptimalEggDrop},
+};
+
+#[test]
+fn constructor() {
+    let game = EggDropGame::new(0, 69, Costs::default(), Some(2)).unwrap();
+    assert_eq!(game.game().get_range(), (0, 69));
+    assert_eq!(game.eggs_left(), Some(2));
+
+    assert!(
+        EggDropGame::new(0, -69, Costs::default(), None).is_err(),
+        "Maximum should be greater than minimum"
+    );
+    assert!(
+        EggDropGame::new(0, 69, Costs::default(), Some(0)).is_err(),
+        "There should be at least 1 egg"
+    );
+    assert!(
+        EggDropGame::new(1, egg_drop::MAX_SIZE as i128 + 1, Costs::default(), None).is_err(),
+        "There should be at most MAX_SIZE numbers"
+    );
+    assert!(EggDropGame::new(1, egg_drop::MAX_SIZE as i128, Costs::default(), None).is_ok());
+}
+
+#[test]
+fn make_guess() {
+    let mut game = EggDropGame::new(0, 10, Costs::new(1, 5), Some(2)).unwrap();
+    game.set_number(5).unwrap();
+
+    assert_eq!(game.make_guess(1), Ok(Ordering::Less));
+    assert_eq!(game.cost(), 1);
+    assert_eq!(game.make_guess(9), Ok(Ordering::Greater));
+    assert_eq!(game.cost(), 6);
+    assert_eq!(game.eggs_left(), Some(1));
+    assert!(!game.is_lost());
+
+    // Breaking the last egg loses the game
+    assert_eq!(game.make_guess(8), Ok(Ordering::Greater));
+    assert!(game.is_lost());
+    assert!(game.make_guess(5).is_err());
+
+    // Resetting the number resets the eggs
+    game.set_number(5).unwrap();
+    assert_eq!(game.eggs_left(), Some(2));
+    assert_eq!(game.cost(), 0);
+}
+
+#[test]
+fn optimal() {
+    for eggs in [Some(1), Some(2), Some(3), None] {
+        for costs in [Costs::default(), Costs::new(1, 4), Costs::new(3, 1)] {
+            let mut game = EggDropGame::new(1, 50, costs, eggs).unwrap();
+
+            for objective in [Objective::WorstCase, Objective::Expected] {
+                let mut strategy = OptimalEggDrop::new(costs, eggs, objective);
+                let mut total = 0;
+                let mut worst = 0;
+
+                // Never runs out of eggs
+                for number in 1..=50 {
+                    game.set_number(number).unwrap();
+                    let cost = game.solve(&mut strategy).unwrap();
+                    total += cost;
+                    worst = worst.max(cost);
+                }
+
+                // The costs match the table
+                match objective {
+                    Objective::WorstCase => assert_eq!(Some(worst as f64), strategy.cost()),
+                    Objective::Expected => {
+                        assert!((total as f64 / 50.0 - strategy.cost().unwrap()).abs() < 1e-9)
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn bisection() {
+    // Bisection runs out of eggs
+    let mut game = EggDropGame::new(1, 100, Costs::default(), Some(1)).unwrap();
+    game.set_number(1).unwrap();
+    assert_eq!(game.solve(&mut Bisection::default()), None);
+    assert!(game.is_lost());
+
+    // Optimal is never worse than bisection
+    let costs = Costs::new(1, 10);
+    let mut game = EggDropGame::new(1, 100, costs, None).unwrap();
+    let mut strategy = OptimalEggDrop::new(costs, None, Objective::WorstCase);
+    strategy.reset(1, 100);
+    let mut worst = 0;
+    for number in 1..=100 {
+        game.set_number(number).unwrap();
+        worst = worst.max(game.solve(&mut Bisection::default()).unwrap());
+    }
+    assert!(strategy.cost().unwrap() <= worst as f64);
+}
+
+#[test]
+#[should_panic]
+fn optimal_too_large() {
+    let mut strategy = OptimalEggDrop::new(Costs::default(), Some(2), Objective::WorstCase);
+    strategy.reset(i128::MIN, i128::MAX);
+}
@@ -0,0 +1,130 @@
+use guess_my_number_rs::equality::{
+    self, EqualityGame, EqualityStrategy, PriorWeighted, RandomOrder, Sequential,
+};
+
+#[test]
+fn constructor() {
+    let game = EqualityGame::new(0, 69).unwrap();
+    assert_eq!(game.get_range(), (0, 69));
+
+    assert!(
+        EqualityGame::new(0, -69).is_err(),
+        "Maximum should be greater than minimum"
+    );
+}
+
+#[test]
+fn make_guess() {
+    let mut game = EqualityGame::new(0, 0).unwrap();
+
+    assert!(!game.make_guess(69));
+    assert!(!game.make_guess(-69));
+    assert!(game.make_guess(0));
+    assert_eq!(game.get_n_guesses(), 3);
+    assert_eq!(game.knowledge().candidates().size(), 1);
+}
+
+#[test]
+fn reset_number_with_prior() {
+    let mut game = EqualityGame::new(1, 10).unwrap();
+
+    // Only 4 can be chosen
+    let mut weights = vec![0.0; 10];
+    weights[3] = 1.0;
+    for _ in 0..10 {
+        game.reset_number_with_prior(&weights).unwrap();
+        assert!(game.make_guess(4));
+    }
+
+    assert!(
+        game.reset_number_with_prior(&[0.0; 10]).is_err(),
+        "Weights should not all be 0"
+    );
+}
+
+/// A strategy that records the guesses of another strategy.
+struct Recorder<'a> {
+    strategy: &'a mut dyn EqualityStrategy,
+    guesses: Vec<i128>,
+}
+
+impl EqualityStrategy for Recorder<'_> {
+    fn reset(&mut self, min: i128, max: i128) {
+        self.guesses.clear();
+        self.strategy.reset(min, max);
+    }
+
+    fn next_guess(&mut self) -> i128 {
+        self.strategy.next_guess()
+    }
+
+    fn update(&mut self, guess: i128, correct: bool) {
+        self.guesses.push(guess);
+        self.strategy.update(guess, correct);
+    }
+}
+
+/// Checks that a strategy finds every number, guessing each number at most once.
+fn check_strategy(strategy: &mut dyn EqualityStrategy) {
+    let mut game = EqualityGame::new(1, 20).unwrap();
+    let mut recorder = Recorder {
+        strategy,
+        guesses: Vec::new(),
+    };
+
+    for number in 1..=20 {
+        game.set_number(number).unwrap();
+        let guesses = game.solve(&mut recorder);
+        assert!(guesses <= 20);
+        assert_eq!(game.knowledge().known(), Some(number));
+
+        let mut history = recorder.guesses.clone();
+        history.sort();
+        history.dedup();
+        assert_eq!(history.len() as u128, guesses);
+    }
+}
+
+#[test]
+fn strategies() {
+    check_strategy(&mut Sequential::default());
+    check_strategy(&mut RandomOrder::default());
+    check_strategy(&mut PriorWeighted::new(vec![1.0; 5]));
+
+    // Sequential takes the distance from the minimum
+    let mut game = EqualityGame::new(1, 20).unwrap();
+    game.set_number(7).unwrap();
+    assert_eq!(game.solve(&mut Sequential::default()), 7);
+
+    // Prior weighted guesses the most likely first
+    game.set_number(15).unwrap();
+    let mut weights = vec![1.0; 20];
+    weights[14] = 2.0;
+    assert_eq!(game.solve(&mut PriorWeighted::new(weights)), 1);
+
+    // The order is sorted again when the range changes
+    let mut strategy = PriorWeighted::new(vec![1.0, 3.0]);
+    let mut game = EqualityGame::new(1, 2).unwrap();
+    for _ in 0..2 {
+        game.set_number(2).unwrap();
+        assert_eq!(game.solve(&mut strategy), 1);
+    }
+
+    let mut game = EqualityGame::new(5, 6).unwrap();
+    game.set_number(6).unwrap();
+    assert_eq!(game.solve(&mut strategy), 1);
+}
+
+#[test]
+fn expected() {
+    assert_eq!(equality::expected_sequential(1), 1.0);
+    assert_eq!(equality::expected_sequential(100), 50.5);
+
+    // Uniform prior is the same as sequential
+    assert_eq!(equality::expected_prior(&[1.0; 100]), 50.5);
+    assert_eq!(equality::expected_prior(&[1.0, 3.0]), 1.25);
+
+    assert_eq!(equality::expected_bisection(1), 1.0);
+    assert_eq!(equality::expected_bisection(7), 17.0 / 7.0);
+    assert!(equality::expected_bisection(100) < (100.0_f32).log2());
+}
@@ -0,0 +1,90 @@
+use guess_my_number_rs::group::{AdaptiveSplitting, GroupGame, Query, QueryKind};
+
+#[test]
+fn constructor() {
+    let game = GroupGame::new(0, 69, 3).unwrap();
+    assert_eq!(game.get_range(), (0, 69));
+    assert_eq!(game.k(), 3);
+    assert_eq!(game.numbers().len(), 3);
+
+    // Every number can be hidden
+    let game = GroupGame::new(1, 5, 5).unwrap();
+    assert_eq!(game.numbers(), vec![1, 2, 3, 4, 5]);
+
+    assert!(
+        GroupGame::new(0, -69, 3).is_err(),
+        "Maximum should be greater than minimum"
+    );
+    assert!(
+        GroupGame::new(0, 69, 0).is_err(),
+        "There should be at least 1 number"
+    );
+    assert!(
+        GroupGame::new(1, 5, 6).is_err(),
+        "There should be enough numbers in the range"
+    );
+}
+
+#[test]
+fn make_query() {
+    let mut game = GroupGame::new(0, 100, 3).unwrap();
+    game.set_numbers(&[10, 20, 30]).unwrap();
+
+    assert_eq!(game.make_query(Query::Below(10)), 0);
+    assert_eq!(game.make_query(Query::Below(11)), 1);
+    assert_eq!(game.make_query(Query::Below(101)), 3);
+    assert_eq!(game.make_query(Query::Any(11, 19)), 0);
+    assert_eq!(game.make_query(Query::Any(15, 25)), 1);
+    assert_eq!(game.make_query(Query::Any(25, 15)), 0);
+    assert_eq!(game.get_n_queries(), 6);
+
+    assert!(game.check(&[30, 10, 20]));
+    assert!(!game.check(&[10, 20]));
+    assert!(!game.check(&[10, 20, 31]));
+
+    assert!(
+        game.set_numbers(&[1, 1, 2]).is_err(),
+        "Numbers should be distinct"
+    );
+    assert!(
+        game.set_numbers(&[1, 2, 101]).is_err(),
+        "Numbers should be within the range"
+    );
+    assert!(
+        game.set_numbers(&[1, 2]).is_err(),
+        "There should be k numbers"
+    );
+    assert_eq!(game.numbers(), vec![10, 20, 30]);
+}
+
+#[test]
+fn adaptive_splitting() {
+    for k in [1, 2, 5, 10] {
+        let mut game = GroupGame::new(1, 1000, k).unwrap();
+
+        for kind in [QueryKind::Below, QueryKind::Any] {
+            for _ in 0..50 {
+                game.reset_numbers();
+                let found = AdaptiveSplitting::new(kind).solve(&mut game);
+
+                assert_eq!(found, game.numbers());
+                // About log2(1000) queries for each number
+                assert!(game.get_n_queries() <= 2 * 10 * k as u128 + 1);
+            }
+        }
+    }
+
+    // Every number is hidden
+    let mut game = GroupGame::new(1, 8, 8).unwrap();
+    let found = AdaptiveSplitting::new(QueryKind::Below).solve(&mut game);
+    assert_eq!(found, game.numbers());
+    assert_eq!(game.get_n_queries(), 0);
+
+    // The full range of numbers doesn't overflow
+    let mut game = GroupGame::new(i128::MIN, i128::MAX, 3).unwrap();
+    let numbers = [i128::MIN, -1, i128::MAX];
+    for kind in [QueryKind::Below, QueryKind::Any] {
+        game.set_numbers(&numbers).unwrap();
+        assert_eq!(AdaptiveSplitting::new(kind).solve(&mut game), numbers);
+    }
+}
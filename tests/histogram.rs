@@ -1,6 +1,8 @@
 use std::collections::BTreeMap;
 
-use guess_my_number_rs::histogram::Histogram;
+use guess_my_number_rs::histogram::{
+    Direction, Histogram, Label, Layout, MultiHistogram, Options, Scale,
+};
 
 #[test]
 fn constructor() {
@@ -45,6 +47,287 @@ fn histogram_range() {
     let map = BTreeMap::from([(69, 69), (420, 420), (21, 4)]);
 
     let histogram = Histogram::new(map.clone());
-    assert_eq!(histogram.get_highest(), (&420, &420));
-    assert_eq!(histogram.get_lowest(), (&21, &4));
+    assert_eq!(histogram.get_highest(), Some((&420, &420)));
+    assert_eq!(histogram.get_lowest(), Some((&21, &4)));
+
+    // Empty histograms
+    let histogram = Histogram::<u32>::new(BTreeMap::new());
+    assert_eq!(histogram.get_highest(), None);
+    assert_eq!(histogram.get_lowest(), None);
+}
+
+#[test]
+fn render_empty() {
+    let histogram = Histogram::<u32>::new(BTreeMap::new());
+
+    for direction in [Direction::Horizontal, Direction::Vertical] {
+        let mut output = String::new();
+        histogram
+            .render(&mut output, &Options::new(direction))
+            .unwrap();
+        assert_eq!(output, "No data.\n");
+    }
+}
+
+#[test]
+fn render() {
+    let mut histogram = Histogram::new(BTreeMap::from([(1, 2), (3, 1)]));
+
+    // Horizontal
+    let mut output = String::new();
+    histogram
+        .render(&mut output, &Options::new(Direction::Horizontal))
+        .unwrap();
+    assert_eq!(output, "    1/2    **\n    3/1    *\n");
+
+    // Vertical
+    let mut output = String::new();
+    histogram
+        .render(&mut output, &Options::new(Direction::Vertical))
+        .unwrap();
+    assert_eq!(output, "  *       \n  *    *  \n  1    3  \n  2    1  \n");
+
+    // Labels and width
+    let options = Options {
+        direction: Direction::Horizontal,
+        width: 2,
+        label: Label::Percent,
+        ..Options::default()
+    };
+    histogram.set_options(options);
+    assert_eq!(histogram.to_string(), " 1/66.7%**\n 3/33.3%*\n");
+
+    let options = Options {
+        label: Label::Key,
+        ..options
+    };
+    histogram.set_options(options);
+    assert_eq!(histogram.to_string(), " 1 **\n 3 *\n");
+
+    // io::Write
+    let mut output = Vec::new();
+    histogram.write(&mut output, &options).unwrap();
+    assert_eq!(output, histogram.to_string().as_bytes());
+}
+
+#[test]
+fn scale() {
+    let histogram = Histogram::new(BTreeMap::from([(1, 1000), (2, 250), (3, 1)]));
+
+    // Bars are only scaled when asked
+    let options = Options {
+        max_width: Some(15),
+        ..Options::default()
+    };
+    let mut output = String::new();
+    histogram.render(&mut output, &options).unwrap();
+    assert!(output.starts_with(&format!("    1/1000 {}\n", "*".repeat(1000))));
+
+    // Horizontal, with 4 characters for the longest bar
+    let options = Options {
+        scale: Scale::Auto,
+        ..options
+    };
+    let mut output = String::new();
+    histogram.render(&mut output, &options).unwrap();
+    assert_eq!(
+        output,
+        "    1/1000 ████\n    2/250  █\n    3/1    \nScale: █ = 250 counts\n"
+    );
+
+    // Vertical, with 2 rows for the highest bar
+    let options = Options {
+        direction: Direction::Vertical,
+        width: 1,
+        label: Label::Key,
+        scale: Scale::Auto,
+        max_height: Some(2),
+        ..Options::default()
+    };
+    let mut output = String::new();
+    histogram.render(&mut output, &options).unwrap();
+    assert_eq!(output, "█  \n█▄ \n123\nScale: █ = 500 counts\n");
+
+    // Fractional blocks
+    let histogram = Histogram::new(BTreeMap::from([(1, 16), (2, 3)]));
+    let options = Options {
+        width: 1,
+        label: Label::Key,
+        scale: Scale::Fit,
+        max_width: Some(4),
+        ..Options::default()
+    };
+    let mut output = String::new();
+    histogram.render(&mut output, &options).unwrap();
+    assert_eq!(output, "1 ██\n2 ▍\nScale: █ = 8 counts\n");
+
+    // Bars that fit are not scaled
+    let options = Options {
+        scale: Scale::Auto,
+        max_width: Some(100),
+        ..options
+    };
+    let mut output = String::new();
+    histogram.render(&mut output, &options).unwrap();
+    assert_eq!(output, format!("1 {}\n2 ***\n", "*".repeat(16)));
+}
+
+#[test]
+fn arithmetic() {
+    let mut first = Histogram::from_vec(vec![1, 2, 2, 3]);
+    let second: Histogram<i32> = vec![2, 3, 3, 4].into_iter().collect();
+
+    first.merge(&second);
+    assert_eq!(
+        first.data(),
+        &BTreeMap::from([(1, 1), (2, 3), (3, 3), (4, 1)])
+    );
+
+    // Entries reaching 0 are removed
+    first.subtract(&Histogram::from_vec(vec![1, 3, 3, 3, 3]));
+    assert_eq!(first.data(), &BTreeMap::from([(2, 3), (4, 1)]));
+
+    // Entries that were already 0 are kept
+    let mut empty = Histogram::new(BTreeMap::from([(1, 0), (2, 2)]));
+    empty.subtract(&Histogram::from_vec(vec![2, 2]));
+    assert_eq!(empty.data(), &BTreeMap::from([(1, 0)]));
+
+    let sum = Histogram::from_vec(vec![1, 2]) + Histogram::from_vec(vec![2]);
+    assert_eq!(sum.data(), &BTreeMap::from([(1, 1), (2, 2)]));
+    let difference = sum - Histogram::from_vec(vec![2]);
+    assert_eq!(difference.data(), &BTreeMap::from([(1, 1), (2, 1)]));
+
+    // Extending
+    let mut histogram = Histogram::from_vec(vec![5]);
+    histogram.extend([5, 6]);
+    assert_eq!(histogram.data(), &BTreeMap::from([(5, 2), (6, 1)]));
+}
+
+#[test]
+fn queries() {
+    let histogram = Histogram::new(BTreeMap::from([(1u128, 1), (2, 2), (3, 5), (4, 2)]));
+
+    assert_eq!(histogram.total(), 10);
+    assert_eq!(histogram.mean(), Some(2.8));
+    assert_eq!(
+        histogram.cumulative(),
+        BTreeMap::from([(&1, 1), (&2, 3), (&3, 8), (&4, 10)])
+    );
+    assert_eq!(histogram.normalise()[&3], 0.5);
+
+    assert_eq!(histogram.quantile(0.0), Some(&1));
+    assert_eq!(histogram.quantile(0.1), Some(&1));
+    assert_eq!(histogram.quantile(0.3), Some(&2));
+    assert_eq!(histogram.percentile(50.0), Some(&3));
+    assert_eq!(histogram.percentile(90.0), Some(&4));
+    assert_eq!(histogram.percentile(100.0), Some(&4));
+    assert_eq!(histogram.percentile(101.0), None);
+
+    // Empty histograms
+    let histogram = Histogram::<u128>::new(BTreeMap::new());
+    assert_eq!(histogram.total(), 0);
+    assert_eq!(histogram.mean(), None);
+    assert_eq!(histogram.quantile(0.5), None);
+}
+
+#[test]
+fn multi_series() {
+    let mut histogram = MultiHistogram::new();
+    histogram.push("First", Histogram::from_vec(vec![1, 1, 2]));
+    histogram.push("Second", Histogram::from_vec(vec![2, 3]));
+    let options = Options {
+        width: 1,
+        ..Options::default()
+    };
+
+    // Keys are shared between series
+    assert_eq!(
+        histogram.keys().into_iter().collect::<Vec<_>>(),
+        vec![&1, &2, &3]
+    );
+
+    let mut output = String::new();
+    histogram
+        .render(&mut output, Layout::Grouped, &options)
+        .unwrap();
+    assert_eq!(
+        output,
+        "1/2**\n  0\n2/1*\n  1#\n3/0\n  1#\nLegend: * = First, # = Second\n"
+    );
+
+    let mut output = String::new();
+    histogram
+        .render(&mut output, Layout::Stacked, &options)
+        .unwrap();
+    assert_eq!(
+        output,
+        "1/2**\n2/2*#\n3/1#\nLegend: * = First, # = Second\n"
+    );
+
+    // Scaled to fit
+    let mut histogram = MultiHistogram::new();
+    histogram.push("First", Histogram::new(BTreeMap::from([(1, 40)])));
+    histogram.push("Second", Histogram::new(BTreeMap::from([(1, 20)])));
+    let options = Options {
+        scale: Scale::Auto,
+        max_width: Some(9),
+        ..options
+    };
+    let mut output = String::new();
+    histogram
+        .render(&mut output, Layout::Stacked, &options)
+        .unwrap();
+    assert_eq!(
+        output,
+        "1/60****##\nLegend: * = First, # = Second\nScale: each symbol = 10 counts\n"
+    );
+
+    // Without series
+    let mut output = String::new();
+    MultiHistogram::<u32>::new()
+        .render(&mut output, Layout::Grouped, &options)
+        .unwrap();
+    assert_eq!(output, "No data.\n");
+}
+
+#[test]
+fn summaries() {
+    let histogram = Histogram::from_vec(vec![1, 2, 2, 3, 3, 3, 4, 20]);
+    let options = Options {
+        width: 2,
+        max_width: Some(22),
+        ..Options::default()
+    };
+
+    let mut output = String::new();
+    histogram.render_ecdf(&mut output, &options).unwrap();
+    assert_eq!(
+        output,
+        " 1/12.5%  █▌\n 2/37.5%  ████▌\n 3/75.0%  █████████\n 4/87.5%  ██████████▌\n20/100.0% ████████████\n"
+    );
+
+    // 20 is beyond the whiskers
+    let mut output = String::new();
+    histogram.render_box_plot(&mut output, &options).unwrap();
+    assert_eq!(
+        output,
+        "|[||                 o\nMin: 1, Q1: 2, Median: 3, Q3: 3, Max: 20, Outliers: 1\n"
+    );
+
+    assert_eq!(histogram.sparkline(), "▃▆█▃▃");
+    let histogram = Histogram::new(BTreeMap::from([(1, 8), (2, 0), (3, 1)]));
+    assert_eq!(histogram.sparkline(), "█ ▁");
+    // Missing keys leave no gap
+    let histogram = Histogram::new(BTreeMap::from([(1, 8), (3, 1)]));
+    assert_eq!(histogram.sparkline(), "█▁");
+
+    // Empty
+    let histogram = Histogram::<u32>::new(BTreeMap::new());
+    let mut output = String::new();
+    histogram.render_ecdf(&mut output, &options).unwrap();
+    assert_eq!(output, "No data.\n");
+    let mut output = String::new();
+    histogram.render_box_plot(&mut output, &options).unwrap();
+    assert_eq!(output, "No data.\n");
+    assert_eq!(histogram.sparkline(), "");
 }
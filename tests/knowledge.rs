@@ -0,0 +1,130 @@
+use guess_my_number_rs::{
+    auto_guesser::{Bisection, Strategy},
+    equality::EqualityGame,
+    knowledge::{CandidateSet, KnowledgeState},
+    NumberGuessingGame,
+};
+use std::cmp::Ordering;
+
+#[test]
+fn candidate_set() {
+    let mut set = CandidateSet::new(1, 10);
+    assert_eq!(set.size(), 10);
+    assert_eq!((set.min(), set.max()), (Some(1), Some(10)));
+    assert_eq!(set.median(), Some(5));
+
+    set.remove(5);
+    set.remove(6);
+    assert_eq!(set.size(), 8);
+    assert!(!set.contains(5));
+    assert!(set.contains(7));
+    assert_eq!(set.nth(4), Some(7));
+    assert_eq!(set.median(), Some(4));
+    assert_eq!(
+        set.iter().collect::<Vec<_>>(),
+        vec![1, 2, 3, 4, 7, 8, 9, 10]
+    );
+
+    set.restrict(3, 8);
+    assert_eq!(set.intervals(), &[(3, 4), (7, 8)]);
+
+    set.retain(|x| x % 2 == 0);
+    assert_eq!(set.intervals(), &[(4, 4), (8, 8)]);
+
+    // Empty set
+    let set = CandidateSet::new(10, 1);
+    assert!(set.is_empty());
+    assert_eq!(set.size(), 0);
+    assert_eq!(set.median(), None);
+    assert_eq!(set.entropy(), 0.0);
+
+    // The full range of numbers doesn't overflow
+    let mut set = CandidateSet::new(i128::MIN, i128::MAX);
+    assert_eq!(set.size(), u128::MAX);
+    assert_eq!(set.nth(0), Some(i128::MIN));
+    assert_eq!(set.nth(u128::MAX), Some(i128::MAX));
+    assert_eq!(set.median(), Some(-1));
+
+    set.remove(0);
+    assert_eq!(set.nth(i128::MAX as u128 + 1), Some(1));
+    assert_eq!(set.nth(u128::MAX), None);
+}
+
+#[test]
+fn observe() {
+    let mut knowledge = KnowledgeState::new(1, 100);
+    assert_eq!(knowledge.size(), 100);
+
+    knowledge.observe(50, Ordering::Greater).unwrap();
+    assert_eq!(knowledge.bounds(), Some((1, 49)));
+    knowledge.observe(25, Ordering::Less).unwrap();
+    assert_eq!(knowledge.bounds(), Some((26, 49)));
+    knowledge.observe_equality(30, false).unwrap();
+    assert_eq!(knowledge.size(), 23);
+    knowledge.observe_predicate(|x| x % 10 == 0, true).unwrap();
+    assert_eq!(knowledge.candidates().intervals(), &[(40, 40)]);
+    assert_eq!(knowledge.known(), Some(40));
+
+    // Contradictions leave the knowledge as it was
+    let before = knowledge.clone();
+    assert!(
+        knowledge.observe(40, Ordering::Less).is_err(),
+        "The number can't be above 40"
+    );
+    assert!(
+        knowledge.observe_equality(40, false).is_err(),
+        "The number can only be 40"
+    );
+    assert_eq!(knowledge, before);
+    knowledge.observe(40, Ordering::Equal).unwrap();
+    assert_eq!(knowledge, before);
+}
+
+#[test]
+fn entropy() {
+    let mut knowledge = KnowledgeState::new(1, 64);
+    assert_eq!(knowledge.entropy(), 6.0);
+
+    knowledge.observe(33, Ordering::Greater).unwrap();
+    assert_eq!(knowledge.entropy(), 5.0);
+    knowledge.observe(32, Ordering::Equal).unwrap();
+    assert_eq!(knowledge.entropy(), 0.0);
+}
+
+#[test]
+fn game_knowledge() {
+    let mut game = NumberGuessingGame::new(1, 100).unwrap();
+    game.set_number(42).unwrap();
+
+    game.make_guess(50, |_, _, _| ());
+    game.make_guess(25, |_, _, _| ());
+    assert_eq!(game.knowledge().bounds(), Some((26, 49)));
+    assert_eq!(game.consistent_range(), (26, 49));
+
+    game.reset_number();
+    assert_eq!(game.knowledge().size(), 100);
+
+    // Wrong guesses leave holes when only equality is told
+    let mut game = EqualityGame::new(1, 10).unwrap();
+    game.set_number(7).unwrap();
+    game.make_guess(3);
+    game.make_guess(5);
+    assert_eq!(
+        game.knowledge().candidates().intervals(),
+        &[(1, 2), (4, 4), (6, 10)]
+    );
+
+    game.reset_number();
+    assert_eq!(game.knowledge().size(), 10);
+}
+
+#[test]
+fn bisection_knowledge() {
+    let mut strategy = Bisection::default();
+    strategy.reset(1, 100);
+    assert_eq!(strategy.next_guess(), 50);
+
+    strategy.update(50, Ordering::Greater);
+    assert_eq!(strategy.knowledge().bounds(), Some((1, 49)));
+    assert_eq!(strategy.next_guess(), 25);
+}
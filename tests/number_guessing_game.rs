@@ -97,3 +97,79 @@ fn range() {
     // Range should stay the same
     assert_eq!(game.get_range(), (69, 420));
 }
+
+#[test]
+fn consistent_range() {
+    let mut game = NumberGuessingGame::new(1, 100).unwrap();
+    game.set_number(42).unwrap();
+    assert_eq!(game.consistent_range(), (1, 100));
+
+    game.make_guess(50, |_, _, _| ());
+    game.make_guess(20, |_, _, _| ());
+    // Guesses outside the range don't change it
+    game.make_guess(90, |_, _, _| ());
+    assert_eq!(game.consistent_range(), (21, 49));
+
+    game.make_guess(42, |_, _, _| ());
+    assert_eq!(game.consistent_range(), (42, 42));
+}
+
+#[test]
+fn hint() {
+    let mut game = NumberGuessingGame::new(1, 100).unwrap();
+    game.set_number(42).unwrap();
+    game.set_hint_budget(2);
+
+    let hint = game.hint().unwrap();
+    assert_eq!((hint.min, hint.max), (1, 100));
+    assert_eq!(hint.guess, 50);
+    assert_eq!(hint.worst_case, 7);
+
+    game.make_guess(50, |_, _, _| ());
+    let hint = game.hint().unwrap();
+    assert_eq!((hint.min, hint.max), (1, 49));
+    assert_eq!(hint.worst_case, 6);
+    assert_eq!(game.get_n_hints(), 2);
+
+    // Should return Result
+    if game.hint().is_ok() {
+        panic!("The hint budget should be used up");
+    }
+
+    // The hints are reset with the number
+    game.reset_number();
+    assert_eq!(game.get_n_hints(), 0);
+}
+
+#[test]
+fn score() {
+    use guess_my_number_rs::{efficiency, number_game::HINT_PENALTY};
+
+    assert_eq!(efficiency::worst_case(1), 1);
+    assert_eq!(efficiency::worst_case(7), 3);
+    assert_eq!(efficiency::worst_case(8), 4);
+    assert_eq!(efficiency::bits_gained(100, 50), 1.0);
+    assert_eq!(efficiency::grade(1.0), 'A');
+    assert_eq!(efficiency::grade(0.6), 'C');
+    assert_eq!(efficiency::grade(0.0), 'F');
+
+    // 7 guesses are optimal for 100 numbers
+    let mut game = NumberGuessingGame::new(1, 100).unwrap();
+    game.set_number(42).unwrap();
+    game.make_guess(42, |_, _, _| ());
+    assert_eq!(game.score(), 100);
+
+    game.set_number(1).unwrap();
+    for guess in 1..=14 {
+        game.make_guess(15 - guess, |_, _, _| ());
+    }
+    assert_eq!(game.score(), 50);
+    game.hint().unwrap();
+    assert_eq!(game.score(), 50 - HINT_PENALTY);
+
+    // The full range of numbers doesn't overflow
+    let mut game = NumberGuessingGame::new(i128::MIN, i128::MAX).unwrap();
+    game.set_number(0).unwrap();
+    game.make_guess(0, |_, _, _| ());
+    assert_eq!(game.score(), 100);
+}
@@ -0,0 +1,54 @@
+use std::cmp::Ordering;
+
+use guess_my_number_rs::parallel::{KarySearch, ParallelGuessingGame};
+
+#[test]
+fn constructor() {
+    let game = ParallelGuessingGame::new(0, 69, 3).unwrap();
+    assert_eq!(game.game().get_range(), (0, 69));
+    assert_eq!(game.k(), 3);
+
+    assert!(
+        ParallelGuessingGame::new(0, -69, 3).is_err(),
+        "Maximum should be greater than minimum"
+    );
+    assert!(
+        ParallelGuessingGame::new(0, 69, 0).is_err(),
+        "k should be greater than 0"
+    );
+}
+
+#[test]
+fn make_guesses() {
+    let mut game = ParallelGuessingGame::new(0, 10, 3).unwrap();
+    game.set_number(5).unwrap();
+
+    let results = game.make_guesses(&[1, 5, 9]).unwrap();
+    assert_eq!(
+        results,
+        vec![Ordering::Less, Ordering::Equal, Ordering::Greater]
+    );
+    assert_eq!(game.get_n_rounds(), 1);
+    assert_eq!(game.get_n_guesses(), 3);
+
+    // Too many or no guesses
+    assert!(game.make_guesses(&[1, 2, 3, 4]).is_err());
+    assert!(game.make_guesses(&[]).is_err());
+    assert_eq!(game.get_n_rounds(), 1);
+}
+
+#[test]
+fn kary_search() {
+    for k in 1..=8 {
+        let mut game = ParallelGuessingGame::new(1, 1000, k).unwrap();
+        // Rounds needed for k + 1 parts each round
+        let rounds = (1001.0_f32.log2() / (k as f32 + 1.0).log2()).ceil() as u128;
+
+        for number in 1..=1000 {
+            game.set_number(number).unwrap();
+            game.solve(&mut KarySearch::default());
+            assert!(game.get_n_rounds() <= rounds);
+            assert!(game.get_n_guesses() <= game.get_n_rounds() * k as u128);
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use std::collections::BTreeMap;
+
+use guess_my_number_rs::{
+    histogram::Histogram,
+    plot::{self, Series},
+};
+
+#[test]
+fn histogram() {
+    let histogram = Histogram::new(BTreeMap::from([(1, 4), (2, 8), (3, 0)]));
+    let svg = plot::histogram(&histogram, "Guesses <1-3>");
+
+    assert!(svg.starts_with("<svg"));
+    assert!(svg.ends_with("</svg>\n"));
+    // Titles are escaped
+    assert!(svg.contains("Guesses &lt;1-3&gt;"));
+    // A bar for every key, with the count when hovered
+    assert_eq!(svg.matches("<title>").count(), 3);
+    assert!(svg.contains("<title>2: 8</title>"));
+}
+
+#[test]
+fn series() {
+    let mut first = Series::new("Bisection");
+    first.push(100.0, 5.8, 1.0);
+    first.push(200.0, 6.8, 1.1);
+    let mut second = Series::new("Sequential");
+    second.push(100.0, 50.0, 29.0);
+    second.push(200.0, 100.0, 58.0);
+
+    let svg = plot::series(&[first, second], "Benchmark", "Max Number", "Guesses");
+    assert!(svg.starts_with("<svg"));
+    assert!(svg.ends_with("</svg>\n"));
+    // A line and a band for every series
+    assert_eq!(svg.matches("<polyline points=\"").count(), 3);
+    assert_eq!(svg.matches("<polygon").count(), 2);
+    assert!(svg.contains(">Sequential</text>"));
+
+    // Without points
+    let svg = plot::series(&[], "Empty", "x", "y");
+    assert!(svg.ends_with("</svg>\n"));
+}
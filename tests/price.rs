@@ -0,0 +1,110 @@
+use guess_my_number_rs::price::{self, Bidder, PriceGame, Risk};
+
+#[test]
+fn constructor() {
+    let game = PriceGame::new(1, 100, 3, 2).unwrap();
+    assert_eq!(game.get_range(), (1, 100));
+    assert_eq!(game.players(), 3);
+    assert_eq!(game.rounds(), 2);
+
+    assert!(
+        PriceGame::new(0, -69, 3, 1).is_err(),
+        "Maximum should be greater than minimum"
+    );
+    assert!(
+        PriceGame::new(1, 100, 0, 1).is_err(),
+        "There should be at least 1 player"
+    );
+    assert!(
+        PriceGame::new(1, 100, 3, 0).is_err(),
+        "There should be at least 1 round"
+    );
+}
+
+#[test]
+fn score() {
+    assert_eq!(PriceGame::score(&[10, 40, 60], 50), Some(1));
+    assert_eq!(PriceGame::score(&[10, 50, 60], 50), Some(1));
+    assert_eq!(PriceGame::score(&[51, 60], 50), None);
+    // The first of the same bids wins
+    assert_eq!(PriceGame::score(&[60, 40, 40], 50), Some(1));
+
+    // Bids far from the number don't overflow
+    assert_eq!(PriceGame::score(&[i128::MIN, 0], i128::MAX), Some(1));
+}
+
+#[test]
+fn make_bids() {
+    let mut game = PriceGame::new(1, 100, 3, 2).unwrap();
+    game.set_number(42).unwrap();
+
+    assert_eq!(game.make_bids(&[20, 30, 50]), Ok(Some(1)));
+    assert_eq!(game.winner(), Some((1, 12)));
+    assert_eq!(game.get_range(), (30, 49));
+    assert!(!game.is_over());
+
+    assert_eq!(game.make_bids(&[45, 40, 41]), Ok(Some(2)));
+    assert_eq!(game.winner(), Some((2, 1)));
+    assert!(game.is_over());
+
+    assert!(
+        game.make_bids(&[42, 42, 42]).is_err(),
+        "The game should be over"
+    );
+
+    // A bid of the number ends the game
+    let mut game = PriceGame::new(1, 100, 2, 5).unwrap();
+    game.set_number(42).unwrap();
+    assert!(game.make_bids(&[42]).is_err(), "Every player should bid");
+    assert_eq!(game.make_bids(&[42, 41]), Ok(Some(0)));
+    assert!(game.is_over());
+    assert_eq!(game.round(), 1);
+}
+
+#[test]
+fn bidders() {
+    assert_eq!(Bidder::new(Risk::Cautious).bid(1, 101, &[]), 26);
+    assert_eq!(Bidder::new(Risk::Balanced).bid(1, 101, &[]), 51);
+    assert_eq!(Bidder::new(Risk::Bold).bid(1, 101, &[]), 76);
+
+    // Takes the widest gap
+    let strategic = Bidder::new(Risk::Strategic);
+    assert_eq!(strategic.bid(1, 100, &[]), 1);
+    assert_eq!(strategic.bid(1, 100, &[40]), 41);
+    assert_eq!(strategic.bid(1, 100, &[60]), 1);
+    assert_eq!(strategic.bid(1, 100, &[30, 90]), 31);
+    assert_eq!(strategic.bid(1, 100, &[1, 90]), 2);
+
+    // The edges of the numbers don't overflow
+    let (min, max) = (i128::MIN, i128::MAX);
+    assert_eq!(Bidder::new(Risk::Cautious).bid(min, max, &[]), min / 2 - 1);
+    assert_eq!(Bidder::new(Risk::Balanced).bid(min, max, &[]), -1);
+    assert_eq!(Bidder::new(Risk::Bold).bid(min, max, &[]), max / 2);
+    assert_eq!(strategic.bid(min, max, &[]), min);
+    assert_eq!(strategic.bid(min, max, &[-1]), 0);
+    assert_eq!(strategic.bid(max - 10, max, &[max - 1]), max - 10);
+    assert_eq!(strategic.bid(max - 10, max, &[max - 8]), max - 7);
+}
+
+#[test]
+fn simulate() {
+    let bidders = [
+        Bidder::new(Risk::Cautious),
+        Bidder::new(Risk::Bold),
+        Bidder::new(Risk::Strategic),
+    ];
+
+    for rounds in [1, 3] {
+        let results = price::simulate(1, 1000, rounds, &bidders, 300).unwrap();
+        assert_eq!(results.len(), 300);
+        for (winner, margin) in results.into_iter().flatten() {
+            assert!(winner < 3);
+            assert!(margin < 1000);
+        }
+    }
+
+    assert!(
+        price::simulate(1, 1000, 1, &[], 300).is_err(),
+        "There should be at least 1 bidder"
+    );
+}
@@ -0,0 +1,107 @@
+use guess_my_number_rs::questions::{self, InformationGain, Predicate, QuestionGame};
+
+#[test]
+fn constructor() {
+    let game = QuestionGame::new(1, 100).unwrap();
+    assert_eq!(game.get_range(), (1, 100));
+    assert_eq!(game.candidates().len(), 100);
+
+    assert!(
+        QuestionGame::new(0, -69).is_err(),
+        "Maximum should be greater than minimum"
+    );
+    assert!(
+        QuestionGame::new(0, i128::MAX).is_err(),
+        "The range is too large to keep every number"
+    );
+}
+
+#[test]
+fn evaluate() {
+    let question = |s: &str| s.parse::<Predicate>().unwrap();
+
+    assert!(question("even?").evaluate(-4));
+    assert!(question("odd?").evaluate(-3));
+    assert!(question("prime?").evaluate(97));
+    assert!(!question("prime?").evaluate(91));
+    assert!(!question("prime?").evaluate(1));
+    assert!(question("square?").evaluate(49));
+    assert!(!question("square?").evaluate(50));
+    // Near the largest number
+    assert!(!question("square?").evaluate(i128::MAX));
+    assert!(!question("prime?").evaluate(i128::MAX - 1));
+    assert!(question("x % 7 == 3").evaluate(24));
+    assert!(question("x % 7 == 3").evaluate(-4));
+    assert!(question("x in 10..40").evaluate(10));
+    assert!(!question("x in 10..40").evaluate(40));
+    assert!(question("digit_sum > 10").evaluate(56));
+    assert!(!question("digit_sum > 10").evaluate(-55));
+    assert!(question("x != 3").evaluate(4));
+}
+
+#[test]
+fn ask() {
+    let mut game = QuestionGame::new(1, 100).unwrap();
+    game.set_number(42).unwrap();
+
+    assert!(game.ask(&"even?".parse().unwrap()));
+    assert_eq!(game.candidates().len(), 50);
+    assert!(!game.ask(&"prime?".parse().unwrap()));
+    assert!(game.ask(&"x in 40..50".parse().unwrap()));
+    assert_eq!(game.candidates(), &[40, 42, 44, 46, 48]);
+    assert!(!game.is_solved());
+    assert!(game.ask(&"digit_sum == 6".parse().unwrap()));
+    assert!(game.is_solved());
+    assert_eq!(game.get_n_questions(), 4);
+
+    assert!(
+        game.set_number(101).is_err(),
+        "Number should be within the range"
+    );
+}
+
+#[test]
+fn information_gain() {
+    // Half of the numbers are even
+    let candidates: Vec<i128> = (1..=8).collect();
+    assert_eq!(
+        InformationGain::information(&Predicate::Even, &candidates),
+        1.0
+    );
+    assert_eq!(
+        InformationGain::information(&"x > 8".parse().unwrap(), &candidates),
+        0.0
+    );
+
+    // Picks the question splitting the numbers in half
+    let strategy = InformationGain::new(vec![
+        "x < 2".parse().unwrap(),
+        "x < 5".parse().unwrap(),
+        "prime?".parse().unwrap(),
+    ]);
+    assert_eq!(
+        strategy.next_question(&candidates),
+        "x < 5".parse().unwrap()
+    );
+
+    // Finds every number with the default pool
+    let mut game = QuestionGame::new(1, 100).unwrap();
+    let strategy = InformationGain::new(Predicate::pool(1, 100));
+    for i in 1..=100 {
+        game.set_number(i).unwrap();
+        // About log2(100) questions
+        assert!(game.solve(&strategy) <= 9);
+    }
+
+    // Primes are only asked about where the answer is exact
+    assert!(Predicate::pool(1, 100).contains(&Predicate::Prime));
+    assert!(Predicate::pool(1, questions::MAX_PRIME).contains(&Predicate::Prime));
+    assert!(!Predicate::pool(1, i128::MAX).contains(&Predicate::Prime));
+
+    // Falls back to splitting at the middle
+    let strategy = InformationGain::new(Vec::new());
+    for i in 1..=100 {
+        game.set_number(i).unwrap();
+        assert!(game.solve(&strategy) <= 7);
+    }
+}
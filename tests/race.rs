@@ -0,0 +1,80 @@
+use guess_my_number_rs::{
+    auto_guesser::{Bisection, Strategy},
+    race::{self, RaceGame, RaceOptimal},
+};
+use std::cmp::Ordering;
+
+#[test]
+fn constructor() {
+    let game = RaceGame::new(0, 69).unwrap();
+    assert_eq!(game.game().get_range(), (0, 69));
+    assert_eq!(game.player(), 0);
+    assert_eq!(game.winner(), None);
+
+    assert!(
+        RaceGame::new(0, -69).is_err(),
+        "Maximum should be greater than minimum"
+    );
+}
+
+#[test]
+fn make_guess() {
+    let mut game = RaceGame::new(1, 100).unwrap();
+    game.set_number(42).unwrap();
+
+    assert_eq!(game.make_guess(50), Ordering::Greater);
+    assert_eq!(game.player(), 1);
+    assert_eq!(game.make_guess(25), Ordering::Less);
+    assert_eq!(game.last_guess(), Some((25, Ordering::Less)));
+    assert_eq!(game.player(), 0);
+    assert_eq!(game.make_guess(42), Ordering::Equal);
+    assert_eq!(game.winner(), Some(0));
+    assert_eq!(game.game().get_n_guesses(), 3);
+
+    game.reset_number();
+    assert_eq!(game.player(), 0);
+    assert_eq!(game.winner(), None);
+    assert_eq!(game.last_guess(), None);
+}
+
+#[test]
+fn race_optimal() {
+    let mut strategy = RaceOptimal::default();
+
+    // The only number always wins
+    strategy.reset(5, 5);
+    assert_eq!(strategy.win_probability(), 1.0);
+    assert_eq!(strategy.next_guess(), 5);
+
+    // Every guess is within the numbers left
+    strategy.reset(1, 100);
+    assert!(strategy.win_probability() >= 0.5 - 1e-9);
+    strategy.update(40, Ordering::Less);
+    strategy.update(60, Ordering::Greater);
+    assert!((41..=59).contains(&strategy.next_guess()));
+}
+
+#[test]
+fn simulate() {
+    let games = 4000;
+
+    // The optimal strategy beats bisection going first and going second
+    let (mut optimal, mut bisection) = (RaceOptimal::default(), Bisection::default());
+    let wins = race::simulate(1, 100, games, &mut optimal, &mut bisection).unwrap();
+    assert_eq!(wins[0] + wins[1], games);
+    let first = wins[0];
+    let wins = race::simulate(1, 100, games, &mut bisection, &mut optimal).unwrap();
+    assert!(first + wins[1] > games);
+
+    assert!(
+        race::simulate(0, -69, games, &mut optimal, &mut bisection).is_err(),
+        "Maximum should be greater than minimum"
+    );
+}
+
+#[test]
+#[should_panic]
+fn simulate_too_large() {
+    let (mut optimal, mut bisection) = (RaceOptimal::default(), Bisection::default());
+    let _ = race::simulate(i128::MIN, i128::MAX, 1, &mut optimal, &mut bisection);
+}
@@ -0,0 +1,85 @@
+use guess_my_number_rs::{
+    auto_guesser::Strategy,
+    unbounded::{BentleyYao, Galloping, UnboundedGame},
+    AutoNumberGuesser,
+};
+
+#[test]
+fn set_number() {
+    let mut game = UnboundedGame::new(Some(1));
+    assert_eq!(game.min(), Some(1));
+
+    game.set_number(69).expect("Valid number shouldn't panic");
+    assert!(
+        game.set_number(0).is_err(),
+        "Number should be at least the minimum"
+    );
+
+    // Without a minimum any number is valid
+    let mut game = UnboundedGame::new(None);
+    game.set_number(i128::MIN)
+        .expect("Valid number shouldn't panic");
+}
+
+#[test]
+fn reset_number() {
+    let mut game = UnboundedGame::new(Some(10));
+    for _ in 0..100 {
+        game.reset_number(5);
+        let mut galloping = Galloping::default();
+        assert!(game.solve(&mut galloping) <= 6);
+    }
+}
+
+/// Checks that a strategy finds every number given.
+fn check_strategy<T: Strategy>(strategy: &mut T, min: Option<i128>, numbers: &[i128]) {
+    let mut game = UnboundedGame::new(min);
+
+    for number in numbers {
+        game.set_number(*number).unwrap();
+        game.solve(strategy);
+        assert!(game.get_n_guesses() > 0);
+    }
+}
+
+#[test]
+fn galloping() {
+    let numbers = [0, 1, 2, 3, 69, 420, 1 << 40, i128::MAX - 1, i128::MAX];
+    check_strategy(&mut Galloping::default(), Some(0), &numbers);
+    check_strategy(&mut Galloping::default(), None, &numbers);
+    check_strategy(&mut Galloping::default(), None, &[-1, -69, i128::MIN]);
+
+    // Takes at most 2 log2(n) + 2 guesses
+    let mut game = UnboundedGame::new(Some(1));
+    for number in 1..=1000 {
+        game.set_number(number).unwrap();
+        let guesses = game.solve(&mut Galloping::default());
+        assert!(guesses as f32 <= 2.0 * (number as f32).log2() + 2.0);
+    }
+}
+
+#[test]
+fn bentley_yao() {
+    let numbers = [0, 1, 2, 3, 69, 420, 1 << 40, i128::MAX - 1, i128::MAX];
+    check_strategy(&mut BentleyYao::default(), Some(0), &numbers);
+    check_strategy(&mut BentleyYao::default(), None, &numbers);
+    check_strategy(&mut BentleyYao::default(), None, &[-1, -69, i128::MIN]);
+
+    // Takes fewer guesses than galloping for large numbers
+    let mut game = UnboundedGame::new(Some(0));
+    game.set_number(1 << 100).unwrap();
+    let bentley_yao = game.solve(&mut BentleyYao::default());
+    let galloping = game.solve(&mut Galloping::default());
+    assert!(bentley_yao < galloping);
+}
+
+#[test]
+fn auto_guesser() {
+    // The strategies also work when the maximum is known
+    let mut game = AutoNumberGuesser::with_strategy(1, 100, Box::<BentleyYao>::default()).unwrap();
+    for number in 1..=100 {
+        game.game_mut().set_number(number).unwrap();
+        game.solve(false);
+        assert!(game.game().get_n_guesses() <= 15);
+    }
+}